@@ -0,0 +1,46 @@
+// Per-verify cost of the strict policy. Run with `cargo bench`.
+//
+// `verify` checks the cofactored equation only. `verify_strict` adds the
+// torsion indices of R and A, looked up with Legendre symbols. Before that,
+// each lookup decoded the point with a scalar multiplication by 1/4, which
+// is what `EdwardsPoint::decompress` still does; the last line estimates
+// that baseline as `verify` plus two such decodes.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use libgoldilocks::{EdwardsPoint, SigningKey};
+use signature::Signer;
+
+const ITERATIONS: u32 = 200;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let key = SigningKey::random(&mut rand::thread_rng());
+    let public = key.verifying_key();
+    let message = b"The quick brown fox jumps over the lazy dog";
+    let sig: libgoldilocks::Signature = key.sign(message);
+    let r: [u8; 57] = sig.as_slice()[..57].try_into().unwrap();
+
+    let verify = time(|| {
+        black_box(public.verify(black_box(message), &sig)).unwrap();
+    });
+    let strict = time(|| {
+        black_box(public.verify_strict(black_box(message), &sig)).unwrap();
+    });
+    let decode = time(|| {
+        black_box(EdwardsPoint::decompress(black_box(&r))).unwrap();
+    });
+
+    println!("verify                  {verify:?}");
+    println!("verify_strict           {strict:?}");
+    println!("verify + 2 full decodes {:?}", verify + 2 * decode);
+}
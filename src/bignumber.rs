@@ -48,17 +48,15 @@ fn bias(mut n: BigNumber, b: Word) -> BigNumber {
     n
 }
 
-#[allow(dead_code)]
+// All ones when the fully carried limbs of n encode a value of at least p.
 pub fn constant_time_greater_or_equal_p(n: &BigNumber) -> Word {
-    let mut ge = LMASK;
-
-    n.iter().take(4).for_each(|x| ge &= x);
-
-    ge = (ge & (n[4] + 1)) | is_zero_mask((n[4] ^ RADIX_MASK) as Word);
-
-    n.iter().take(8).skip(5).for_each(|x| ge &= x);
+    // The final borrow of n - p is zero exactly when n >= p.
+    let mut scarry = 0 as Sdword;
+    for i in 0..N_LIMBS {
+        scarry = (scarry + n[i] as Sdword - MODULUS[i] as Sdword) >> RADIX;
+    }
 
-    !is_zero_mask((ge ^ RADIX_MASK) as Word)
+    is_zero_mask(scarry as Word)
 }
 
 pub fn invert(x: &BigNumber) -> BigNumber {
@@ -567,7 +565,8 @@ pub fn decaf_cond_negate(n: &BigNumber, neg: &Word) -> BigNumber {
     decaf_const_time_select(n, &m, neg)
 }
 
-#[allow(dead_code)]
+// Unpacks 56 little-endian bytes, with an all-ones mask when they encode
+// a value below p.
 pub fn deserialize_return_mask(inp: Serialized) -> (BigNumber, Word) {
     let mut n = create_zero_bignumber();
 
@@ -581,10 +580,9 @@ pub fn deserialize_return_mask(inp: Serialized) -> (BigNumber, Word) {
         n[2 * i + 1] = (out >> 28) as Word;
     }
 
-    (n, constant_time_greater_or_equal_p(&n))
+    (n, !constant_time_greater_or_equal_p(&n))
 }
 
-#[allow(dead_code)]
 pub fn deserialize(inp: Serialized) -> (BigNumber, bool) {
     let (n, mask) = deserialize_return_mask(inp);
     let ok = mask == LMASK;
@@ -627,9 +625,8 @@ pub fn dsa_like_deserialize(input: &[u8], mask: usize) -> (BigNumber, Word) {
         }
         fill -= RADIX;
         buffer >>= RADIX;
-        // TODO CHECK SCARRY NOT SURE IF CORRECT
-        scarry = ((u32::wrapping_sub(u32::wrapping_add(scarry as Word, n[i]), MODULUS[i]) >> 8) * 4)
-            as Sdword;
+        // Borrow of n - p, which ends up -1 exactly when n < p.
+        scarry = (scarry + n[i] as Sdword - MODULUS[i] as Sdword) >> WORD_BITS;
     }
 
     (
@@ -708,6 +705,16 @@ mod tests {
                 0xfffffff, 0xfffffff
             ]
         );
+
+        // p - 1 and 2^224 - 1 are canonical, p + 1 = 2^448 - 2^224 is not.
+        ser[0] = 0xfe;
+        assert!(deserialize(ser).1);
+        ser = [0; FIELD_BYTES];
+        ser[..28].copy_from_slice(&[0xff; 28]);
+        assert!(deserialize(ser).1);
+        ser = [0xff; FIELD_BYTES];
+        ser[..28].copy_from_slice(&[0; 28]);
+        assert!(!deserialize(ser).1);
     }
 
     #[test]
@@ -913,6 +920,14 @@ mod tests {
         let (dst, ok) = dsa_like_deserialize(&ser, 0);
         assert_eq!(dst, exp);
         assert_eq!(ok, DECAF_TRUE);
+
+        let mut modulus: [u8; 57] = [0xff; 57];
+        modulus[28] = 0xfe;
+        let (_, ok) = dsa_like_deserialize(&modulus, 0);
+        assert_eq!(ok, 0);
+        modulus[0] = 0xfe;
+        let (_, ok) = dsa_like_deserialize(&modulus, 0);
+        assert_eq!(ok, DECAF_TRUE);
     }
 
     #[test]
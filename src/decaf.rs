@@ -0,0 +1,320 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::errors::LibgoldilockErrors;
+use crate::extended_point::{
+    decaf_decode, decaf_map_to_curve, precomputed_scalar_mul, TwistedExtendedPoint,
+};
+use crate::Scalar;
+
+/// An element of the Decaf448 group of RFC 9496: Ed448 modulo its 4-torsion,
+/// a group of prime order L with a canonical 56-byte encoding.
+///
+/// Points that differ by 4-torsion are the same group element, so equality
+/// compares classes rather than coordinates.
+#[derive(Clone)]
+pub struct Decaf448Point(TwistedExtendedPoint);
+
+impl Decaf448Point {
+    pub fn identity() -> Decaf448Point {
+        let mut p = TwistedExtendedPoint::new();
+        p.set_identity();
+        Decaf448Point(p)
+    }
+
+    /// The RFC 9496 generator, encoded as 0x66 repeated 28 times and then
+    /// 0x33 repeated 28 times.
+    pub fn generator() -> Decaf448Point {
+        // The comb table is built on the negation of this generator.
+        Decaf448Point(precomputed_scalar_mul(Scalar::ONE.0).negate())
+    }
+
+    /// [s] times the generator, using the precomputed table.
+    pub fn mul_base(scalar: &Scalar) -> Decaf448Point {
+        Decaf448Point(precomputed_scalar_mul((-*scalar).0))
+    }
+
+    /// Decodes a 56-byte RFC 9496 encoding. Encodings of values that are
+    /// at least p or negative are rejected, as are those that do not decode
+    /// to a point.
+    pub fn decode(bytes: &[u8; 56]) -> Result<Decaf448Point, LibgoldilockErrors> {
+        Ok(Decaf448Point(decaf_decode(bytes)?))
+    }
+
+    /// The canonical 56-byte RFC 9496 encoding.
+    pub fn encode(&self) -> [u8; 56] {
+        self.0.decaf_encode()
+    }
+
+    /// The RFC 9496 one-way map from 112 uniformly random bytes, such as a
+    /// SHAKE256 output, to a group element.
+    pub fn from_uniform_bytes(bytes: &[u8; 112]) -> Decaf448Point {
+        let mut first: [u8; 56] = [0; 56];
+        let mut second: [u8; 56] = [0; 56];
+        first.copy_from_slice(&bytes[..56]);
+        second.copy_from_slice(&bytes[56..]);
+
+        Decaf448Point(decaf_map_to_curve(&first).add(&decaf_map_to_curve(&second)))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Decaf448Point::identity()
+    }
+}
+
+impl Default for Decaf448Point {
+    fn default() -> Self {
+        Decaf448Point::identity()
+    }
+}
+
+impl PartialEq for Decaf448Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.mod_equal(&other.0)
+    }
+}
+
+impl Eq for Decaf448Point {}
+
+impl fmt::Debug for Decaf448Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Decaf448Point({})", hex::encode(self.encode()))
+    }
+}
+
+impl Add<&Decaf448Point> for &Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn add(self, other: &Decaf448Point) -> Decaf448Point {
+        Decaf448Point(self.0.add(&other.0))
+    }
+}
+
+impl Add for Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn add(self, other: Decaf448Point) -> Decaf448Point {
+        &self + &other
+    }
+}
+
+impl Sub<&Decaf448Point> for &Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn sub(self, other: &Decaf448Point) -> Decaf448Point {
+        let mut p = self.0.clone();
+        p.sub_projected_niels_from_extended_point(&other.0.to_projected_niels(), false);
+        Decaf448Point(p)
+    }
+}
+
+impl Sub for Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn sub(self, other: Decaf448Point) -> Decaf448Point {
+        &self - &other
+    }
+}
+
+impl Neg for &Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn neg(self) -> Decaf448Point {
+        Decaf448Point(self.0.negate())
+    }
+}
+
+impl Neg for Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn neg(self) -> Decaf448Point {
+        -&self
+    }
+}
+
+impl Mul<&Scalar> for &Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn mul(self, scalar: &Scalar) -> Decaf448Point {
+        Decaf448Point(self.0.point_scalar_mul(&scalar.0))
+    }
+}
+
+impl Mul<Scalar> for Decaf448Point {
+    type Output = Decaf448Point;
+
+    fn mul(self, scalar: Scalar) -> Decaf448Point {
+        &self * &scalar
+    }
+}
+
+impl Mul<Decaf448Point> for Scalar {
+    type Output = Decaf448Point;
+
+    fn mul(self, point: Decaf448Point) -> Decaf448Point {
+        &point * &self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bignumber::{
+        add, decaf_equal, dsa_like_serialize, isr, low_bit, mul, mul_with_signed_curve_constant,
+        neg, square,
+    };
+    use crate::constants32::{BIG_ONE, BIG_ZERO, DECAF_TRUE, EDWARDS_D};
+
+    fn decode_hex(s: &str) -> Result<Decaf448Point, LibgoldilockErrors> {
+        let bytes: [u8; 56] = hex::decode(s).unwrap().try_into().unwrap();
+        Decaf448Point::decode(&bytes)
+    }
+
+    #[test]
+    pub fn test_generator_multiples() {
+        // The multiples of the generator from RFC 9496, appendix B.
+        let multiples = [
+            "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "6666666666666666666666666666666666666666666666666666666633333333333333333333333333333333333333333333333333333333",
+            "c898eb4f87f97c564c6fd61fc7e49689314a1f818ec85eeb3bd5514ac816d38778f69ef347a89fca817e66defdedce178c7cc709b2116e75",
+            "a0c09bf2ba7208fda0f4bfe3d0f5b29a543012306d43831b5adc6fe7f8596fa308763db15468323b11cf6e4aeb8c18fe44678f44545a69bc",
+            "b46f1836aa287c0a5a5653f0ec5ef9e903f436e21c1570c29ad9e5f596da97eeaf17150ae30bcb3174d04bc2d712c8c7789d7cb4fda138f4",
+            "1c5bbecf4741dfaae79db72dface00eaaac502c2060934b6eaaeca6a20bd3da9e0be8777f7d02033d1b15884232281a41fc7f80eed04af5e",
+            "86ff0182d40f7f9edb7862515821bd67bfd6165a3c44de95d7df79b8779ccf6460e3c68b70c16aaa280f2d7b3f22d745b97a89906cfc476c",
+            "502bcb6842eb06f0e49032bae87c554c031d6d4d2d7694efbf9c468d48220c50f8ca28843364d70cee92d6fe246e61448f9db9808b3b2408",
+            "0c9810f1e2ebd389caa789374d78007974ef4d17227316f40e578b336827da3f6b482a4794eb6a3975b971b5e1388f52e91ea2f1bcb0f912",
+            "20d41d85a18d5657a29640321563bbd04c2ffbd0a37a7ba43a4f7d263ce26faf4e1f74f9f4b590c69229ae571fe37fa639b5b8eb48bd9a55",
+            "e6b4b8f408c7010d0601e7eda0c309a1a42720d6d06b5759fdc4e1efe22d076d6c44d42f508d67be462914d28b8edce32e7094305164af17",
+            "be88bbb86c59c13d8e9d09ab98105f69c2d1dd134dbcd3b0863658f53159db64c0e139d180f3c89b8296d0ae324419c06fa87fc7daaf34c1",
+            "a456f9369769e8f08902124a0314c7a06537a06e32411f4f93415950a17badfa7442b6217434a3a05ef45be5f10bd7b2ef8ea00c431edec5",
+            "186e452c4466aa4383b4c00210d52e7922dbf9771e8b47e229a9b7b73c8d10fd7ef0b6e41530f91f24a3ed9ab71fa38b98b2fe4746d51d68",
+            "4ae7fdcae9453f195a8ead5cbe1a7b9699673b52c40ab27927464887be53237f7f3a21b938d40d0ec9e15b1d5130b13ffed81373a53e2b43",
+            "841981c3bfeec3f60cfeca75d9d8dc17f46cf0106f2422b59aec580a58f342272e3a5e575a055ddb051390c54c24c6ecb1e0aceb075f6056",
+        ];
+
+        let g = Decaf448Point::generator();
+        let mut p = Decaf448Point::identity();
+        for (i, encoded) in multiples.iter().enumerate() {
+            let k = Scalar::from(i as u64);
+            assert_eq!(hex::encode(p.encode()), *encoded);
+            assert_eq!(decode_hex(encoded).unwrap(), p);
+            assert_eq!(&g * &k, p);
+            assert_eq!(Decaf448Point::mul_base(&k), p);
+            p = &p + &g;
+        }
+    }
+
+    #[test]
+    pub fn test_invalid_encodings() {
+        // Non-canonical field encodings from RFC 9496, appendix B: each is
+        // at least p and reduces to a negative field element.
+        let non_canonical = [
+            "8e24f838059ee9fef1e209126defe53dcd74ef9b6304601c6966099effffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "86fcc7212bd4a0b980928666dc28c444a605ef38e09fb569e28d4443ffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "866d54bd4c4ff41a55d4eefdbeca73cbd653c7bd3135b383708ec0bdffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "4a380ccdab9c86364a89e77a464d64f9157538cfdfa686adc0d5ece4ffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ];
+        // Negative field elements: 1 and p - 2.
+        let negative = [
+            "0100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "fdfffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ];
+        for encoded in non_canonical.iter().chain(negative.iter()) {
+            assert!(matches!(
+                decode_hex(encoded),
+                Err(LibgoldilockErrors::NonCanonicalEncodingError)
+            ));
+        }
+
+        // 4 is a canonical, non-negative field element that is not the
+        // encoding of any point.
+        assert!(matches!(
+            decode_hex(&format!("04{}", "00".repeat(55))),
+            Err(LibgoldilockErrors::DecodeError)
+        ));
+    }
+
+    #[test]
+    pub fn test_decode_rule() {
+        // The decoding rule of RFC 9496, section 5.3.1, for small s: s must
+        // not be negative, and u2 u1^2 must be a square, where u1 = 1 + s^2
+        // and u2 = u1^2 - 4 d s^2.
+        let mut non_squares = 0;
+        for i in 0..256 {
+            let mut s = BIG_ZERO;
+            s[0] = i;
+            let result = Decaf448Point::decode(&dsa_like_serialize(&s));
+            if low_bit(&s) == DECAF_TRUE {
+                assert!(matches!(
+                    result,
+                    Err(LibgoldilockErrors::NonCanonicalEncodingError)
+                ));
+                continue;
+            }
+            // -s is negative for every non-zero even s, as p is odd.
+            if i != 0 {
+                assert!(matches!(
+                    Decaf448Point::decode(&dsa_like_serialize(&neg(&s))),
+                    Err(LibgoldilockErrors::NonCanonicalEncodingError)
+                ));
+            }
+
+            let s2 = square(&s);
+            let u1 = add(&BIG_ONE, &s2);
+            let u2 = add(
+                &square(&u1),
+                &mul_with_signed_curve_constant(&s2, &(-4 * EDWARDS_D)),
+            );
+            let v = mul(&u2, &square(&u1));
+            if decaf_equal(&mul(&v, &square(&isr(&v))), &BIG_ONE) == DECAF_TRUE {
+                assert_eq!(result.unwrap().encode(), dsa_like_serialize(&s));
+            } else {
+                non_squares += 1;
+                assert!(matches!(result, Err(LibgoldilockErrors::DecodeError)));
+            }
+        }
+        assert!(non_squares > 0);
+    }
+
+    #[test]
+    pub fn test_from_uniform_bytes() {
+        // Group elements from uniform byte strings, RFC 9496, appendix B.
+        let vectors = [
+            (
+                "cbb8c991fd2f0b7e1913462d6463e4fd2ce4ccdd28274dc2ca1f4165d5ee6cdccea57be3416e166fd06718a31af45a2f8e987e301be59ae6673e963001dbbda80df47014a21a26d6c7eb4ebe0312aa6fffb8d1b26bc62ca40ed51f8057a635a02c2b8c83f48fa6a2d70f58a1185902c0",
+                "0c709c9607dbb01c94513358745b7c23953d03b33e39c7234e268d1d6e24f34014ccbc2216b965dd231d5327e591dc3c0e8844ccfd568848",
+            ),
+            (
+                "b6d8da654b13c3101d6634a231569e6b85961c3f4b460a08ac4a5857069576b64428676584baa45b97701be6d0b0ba18ac28d443403b45699ea0fbd1164f5893d39ad8f29e48e399aec5902508ea95e33bc1e9e4620489d684eb5c26bc1ad1e09aba61fabc2cdfee0b6b6862ffc8e55a",
+                "76ab794e28ff1224c727fa1016bf7f1d329260b7218a39aea2fdb17d8bd9119017b093d641cedf74328c327184dc6f2a64bd90eddccfcdab",
+            ),
+        ];
+        for (input, output) in vectors.iter() {
+            let bytes: [u8; 112] = hex::decode(input).unwrap().try_into().unwrap();
+            let p = Decaf448Point::from_uniform_bytes(&bytes);
+            assert_eq!(hex::encode(p.encode()), *output);
+            assert_eq!(decode_hex(output).unwrap(), p);
+        }
+    }
+
+    #[test]
+    pub fn test_group_laws() {
+        let g = Decaf448Point::generator();
+        let id = Decaf448Point::identity();
+        let a = Scalar::random(&mut rand::thread_rng());
+        let b = Scalar::random(&mut rand::thread_rng());
+
+        assert!(id.is_identity());
+        assert!((&g - &g).is_identity());
+        assert_eq!(&g + &id, g);
+        assert_eq!(-(-g.clone()), g);
+        assert_eq!(&g * &(-Scalar::ONE), -&g);
+        assert_eq!(&(&g * &a) + &(&g * &b), Decaf448Point::mul_base(&(a + b)));
+        assert_eq!(&(&g * &a) * &b, &(&g * &b) * &a);
+        assert_eq!(a * g.clone(), g.clone() * a);
+
+        let p = &g * &a;
+        assert_eq!(Decaf448Point::decode(&p.encode()).unwrap(), p);
+        assert_ne!(p, g);
+    }
+}
@@ -1,11 +1,19 @@
+use rand::{CryptoRng, RngCore};
 use sha3::{
     digest::{ExtendableOutput, Update, XofReader},
     Shake256,
 };
 
 use crate::{
-    extended_point::{eddsa_like_decode, TwistedExtendedPoint},
-    scalar::{decode_long, sub, Scalar},
+    bignumber::{self, decaf_equal, isr, mul_with_signed_curve_constant, neg, square},
+    constants32::{BigNumber, BIG_ONE, BIG_ZERO, DECAF_TRUE, EDWARDS_D},
+    extended_point::{
+        double_scalarmul_non_secret_with_table, eddsa_like_decode_times_four,
+        eddsa_like_decompress, multi_scalarmul_non_secret, TwistedExtendedPoint,
+        TwistedProjectedNiels,
+    },
+    scalar::{self, decode_canonical, decode_long, decode_short, encode, sub, Scalar},
+    VerificationPolicy,
 };
 
 use crate::errors::LibgoldilockErrors;
@@ -23,90 +31,414 @@ pub fn sha3(input: &[u8], output: &mut [u8]) {
     reader.read(output);
 }
 
-pub fn hash_with_dom(input: &mut Vec<u8>, output: &mut [u8]) {
-    let mut sig = b"SigEd448".to_vec();
-    sig.push(0);
-    sig.push(0);
-    sig.append(input);
+pub const MAX_CONTEXT_LENGTH: usize = 255;
+
+pub fn check_context(context: &[u8]) -> Result<(), LibgoldilockErrors> {
+    if context.len() > MAX_CONTEXT_LENGTH {
+        return Err(LibgoldilockErrors::ContextTooLongError {
+            max: MAX_CONTEXT_LENGTH,
+            actual: context.len(),
+        });
+    }
+    Ok(())
+}
+
+pub const PREHASH_LENGTH: usize = 64;
+
+pub fn check_prehash(prehash: &[u8]) -> Result<(), LibgoldilockErrors> {
+    if prehash.len() != PREHASH_LENGTH {
+        return Err(LibgoldilockErrors::InvalidPrehashLengthError {
+            expected: PREHASH_LENGTH,
+            actual: prehash.len(),
+        });
+    }
+    Ok(())
+}
+
+// PH(M) = SHAKE256(M, 64) for Ed448ph.
+pub fn prehash(message: &[u8]) -> [u8; PREHASH_LENGTH] {
+    let mut out: [u8; PREHASH_LENGTH] = [0; PREHASH_LENGTH];
+    sha3(message, &mut out);
+    out
+}
+
+// dom4(phflag, context) from RFC 8032, section 2.
+pub fn dom_hasher(prehashed: bool, context: &[u8]) -> Shake256 {
     let mut hasher = Shake256::default();
-    hasher.update(&sig);
-    let mut reader = hasher.finalize_xof();
-    reader.read(output);
+    hasher.update(b"SigEd448");
+    hasher.update(&[prehashed as u8, context.len() as u8]);
+    hasher.update(context);
+    hasher
 }
 
-// pub fn dsa_sign(sym: &[u8]) {}
+// SHAKE256(dom4 || head || message) without concatenating the inputs.
+pub fn hash_with_dom(
+    head: &[&[u8]],
+    message: &[&[u8]],
+    output: &mut [u8],
+    prehashed: bool,
+    context: &[u8],
+) {
+    let mut hasher = dom_hasher(prehashed, context);
+    head.iter().for_each(|part| hasher.update(part));
+    message.iter().for_each(|part| hasher.update(part));
+    hasher.finalize_xof_into(output);
+}
 
-pub fn dsa_verify(pubkey: &[u8], sig: &[u8], message: &[u8]) -> Result<bool, LibgoldilockErrors> {
-    let p: TwistedExtendedPoint;
-    match eddsa_like_decode(pubkey) {
-        Ok(point) => p = point,
+// Public keys and R values are decoded to [4] times their prime-order
+// component, which is what the verification equation needs.
+pub fn decode_pubkey(pubkey: &[u8]) -> Result<TwistedExtendedPoint, LibgoldilockErrors> {
+    match eddsa_like_decode_times_four(pubkey) {
+        Ok(point) => Ok(point),
         Err(err) => match err {
-            LibgoldilockErrors::InvalidLengthError => {
-                return Err(LibgoldilockErrors::InvalidPubkeyLengthError);
+            LibgoldilockErrors::InvalidLengthError { expected, actual } => {
+                Err(LibgoldilockErrors::InvalidPubkeyLengthError { expected, actual })
             }
-            LibgoldilockErrors::DecodeError => {
-                return Err(LibgoldilockErrors::DecodePubkeyError);
-            }
-            _ => {
-                panic!("Unexpected error type");
+            LibgoldilockErrors::DecodeError => Err(LibgoldilockErrors::DecodePubkeyError),
+            LibgoldilockErrors::NonCanonicalEncodingError => {
+                Err(LibgoldilockErrors::NonCanonicalPubkeyError)
             }
+            err => Err(err),
         },
     }
+}
+
+pub const SIGNATURE_LENGTH: usize = 114;
+
+// A signature is exactly R || S; anything else is rejected before R or S is
+// looked at.
+pub fn check_signature_length(sig: &[u8]) -> Result<(), LibgoldilockErrors> {
+    if sig.len() != SIGNATURE_LENGTH {
+        return Err(LibgoldilockErrors::InvalidSignatureLengthError {
+            expected: SIGNATURE_LENGTH,
+            actual: sig.len(),
+        });
+    }
+    Ok(())
+}
+
+fn signature_point_error(err: LibgoldilockErrors) -> LibgoldilockErrors {
+    match err {
+        LibgoldilockErrors::DecodeError => LibgoldilockErrors::DecodeSignatureError,
+        LibgoldilockErrors::NonCanonicalEncodingError => {
+            LibgoldilockErrors::NonCanonicalSignatureError
+        }
+        err => err,
+    }
+}
+
+pub fn decode_signature_point(sig: &[u8]) -> Result<TwistedExtendedPoint, LibgoldilockErrors> {
+    check_signature_length(sig)?;
+    eddsa_like_decode_times_four(&sig[..57]).map_err(signature_point_error)
+}
+
+// Strict parsing of R || S without verifying anything: R must be the
+// canonical encoding of a curve point and S must be below L.
+pub fn check_signature_encoding(sig: &[u8]) -> Result<(), LibgoldilockErrors> {
+    check_signature_length(sig)?;
+    eddsa_like_decompress(&sig[..57]).map_err(signature_point_error)?;
+    decode_signature_scalar(sig)?;
+    Ok(())
+}
+
+// S must be below L, otherwise S + L would verify as a second signature.
+pub fn decode_signature_scalar(sig: &[u8]) -> Result<Scalar, LibgoldilockErrors> {
+    check_signature_length(sig)?;
+    decode_canonical(&sig[57..SIGNATURE_LENGTH])
+}
+
+// The signature with S replaced by S + L, the second encoding of the same
+// response that `decode_signature_scalar` must reject.
+#[cfg(test)]
+pub fn add_group_order_to_scalar(sig: &[u8; 114]) -> [u8; 114] {
+    let mut out = *sig;
+    let mut carry = 0u64;
+    for (i, word) in crate::constants32::SCALAR_Q.iter().enumerate() {
+        for (j, byte) in word.to_le_bytes().iter().enumerate() {
+            let pos = 57 + 4 * i + j;
+            carry += out[pos] as u64 + *byte as u64;
+            out[pos] = carry as u8;
+            carry >>= 8;
+        }
+    }
+    out[113] += carry as u8;
+    out
+}
+
+// Checks [4][S]B = [4]R + [4][k]A for the decoded public key, R and S and the
+// 114-byte challenge hash k.
+pub fn verify_equation(
+    p: &TwistedExtendedPoint,
+    r_point: &TwistedExtendedPoint,
+    response: &Scalar,
+    challenge: &[u8],
+) -> bool {
+    let table = pubkey_wnaf_table(p);
+    verify_equation_with_table(&table, r_point, response, challenge)
+}
+
+// The wNAF table of the decoded public key used by `verify_equation_with_table`.
+pub fn pubkey_wnaf_table(p: &TwistedExtendedPoint) -> Vec<TwistedProjectedNiels> {
+    p.prepare_wnaf_table()
+}
+
+pub fn verify_equation_with_table(
+    table: &[TwistedProjectedNiels],
+    r_point: &TwistedExtendedPoint,
+    response: &Scalar,
+    challenge: &[u8],
+) -> bool {
     let scalar_zero: Scalar = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-    let scalar_four: Scalar = [4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-    let mut p2 = p.clone();
-    p2 = p2.point_scalar_mul(&scalar_four);
-    let mut sig1: [u8; 57] = [0; 57];
-    let mut sig2: [u8; 57] = [0; 57];
-
-    // for i in 0..57 {
-    //     sig1[i] = sig[i];
-    //     sig2[i] = sig[i + 57];
-    // }
-
-    sig1[..57].copy_from_slice(&sig[..57]);
-    sig2[..57].copy_from_slice(&sig[57..(57 + 57)]);
-
-    let mut r_point: TwistedExtendedPoint;
-    match eddsa_like_decode(&sig1) {
-        Ok(point) => r_point = point,
-        Err(err) => match err {
-            LibgoldilockErrors::InvalidLengthError => {
-                return Err(LibgoldilockErrors::InvalidSignatureLengthError);
-            }
-            LibgoldilockErrors::DecodeError => {
-                return Err(LibgoldilockErrors::DecodeSignatureError);
-            }
-            _ => {
-                panic!("Unexpected error type");
-            }
-        },
+
+    let mut challenge_scalar = decode_long(challenge);
+    challenge_scalar = sub(&scalar_zero, &challenge_scalar);
+
+    let pk = double_scalarmul_non_secret_with_table(table, response, &challenge_scalar);
+
+    r_point.mod_equal(&pk)
+}
+
+// Returns i such that the encoded point is its prime-order component plus
+// [i](1, 0). The point (1, 0) generates the 4-torsion of the untwisted curve.
+//
+// The curve group is cyclic of order 4L, so i is read off with Legendre
+// symbols instead of a scalar multiplication. A point is a double exactly
+// when y^2 - 1 is a square, which settles whether i is odd; adding (1, 0),
+// which maps (x, y) to (y, -x), makes it even. An even point other than
+// (0, +-1) is then a multiple of four exactly when its halves are doubles,
+// which for s^2 = (1 - d y^2) / (1 - d) comes down to (1 - y)(1 + s) not
+// being a square.
+pub fn torsion_index(encoded: &[u8]) -> Result<u32, LibgoldilockErrors> {
+    let (mut x, mut y) = eddsa_like_decompress(encoded)?;
+
+    let odd = is_non_square(&bignumber::sub(&square(&y), &BIG_ONE));
+    if odd {
+        (x, y) = (y, neg(&x));
+    }
+
+    let even = if decaf_equal(&x, &BIG_ZERO) == DECAF_TRUE {
+        if decaf_equal(&y, &BIG_ONE) == DECAF_TRUE {
+            0
+        } else {
+            2
+        }
+    } else {
+        let u = bignumber::sub(
+            &BIG_ONE,
+            &mul_with_signed_curve_constant(&square(&y), &EDWARDS_D),
+        );
+        let s = bignumber::mul(
+            &u,
+            &isr(&mul_with_signed_curve_constant(&u, &(1 - EDWARDS_D))),
+        );
+        if is_non_square(&bignumber::mul(
+            &bignumber::sub(&BIG_ONE, &y),
+            &bignumber::add(&BIG_ONE, &s),
+        )) {
+            0
+        } else {
+            2
+        }
+    };
+
+    Ok(if odd { (even + 3) % 4 } else { even })
+}
+
+// Whether a is not a square, from Euler's criterion a^((p - 1) / 2) = -1.
+fn is_non_square(a: &BigNumber) -> bool {
+    let euler = bignumber::mul(a, &square(&isr(a)));
+    decaf_equal(&euler, &neg(&BIG_ONE)) == DECAF_TRUE
+}
+
+// Decoding keeps only the prime-order component, so a point has small order
+// exactly when it decodes to the identity.
+pub fn is_small_order(point: &TwistedExtendedPoint) -> bool {
+    point.is_identity()
+}
+
+pub fn is_torsion_free(encoded: &[u8]) -> Result<bool, LibgoldilockErrors> {
+    Ok(torsion_index(encoded)? == 0)
+}
+
+// Full check for a public key from an untrusted source: canonical encoding,
+// on the curve, not of small order and in the prime-order subgroup.
+pub fn validate_pubkey(pubkey: &[u8]) -> Result<(), LibgoldilockErrors> {
+    let p = decode_pubkey(pubkey)?;
+    if is_small_order(&p) {
+        return Err(LibgoldilockErrors::SmallOrderPubkeyError);
     }
+    if !is_torsion_free(pubkey)? {
+        return Err(LibgoldilockErrors::TorsionPubkeyError);
+    }
+    Ok(())
+}
+
+pub fn dsa_verify(
+    pubkey: &[u8],
+    sig: &[u8],
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
+) -> Result<bool, LibgoldilockErrors> {
+    dsa_verify_with_policy(
+        pubkey,
+        sig,
+        message,
+        prehashed,
+        context,
+        &VerificationPolicy::RFC8032,
+    )
+}
+
+pub fn dsa_verify_with_policy(
+    pubkey: &[u8],
+    sig: &[u8],
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
+    policy: &VerificationPolicy,
+) -> Result<bool, LibgoldilockErrors> {
+    check_context(context)?;
+
+    let p = decode_pubkey(pubkey)?;
+    let prepared = PreparedPubkey {
+        table: pubkey_wnaf_table(&p),
+        point: p,
+        torsion: None,
+    };
+    dsa_verify_prepared(pubkey, &prepared, sig, message, prehashed, context, policy)
+}
+
+// A decoded public key with the data verification derives from it alone.
+// `torsion` is only computed up front for keys that are reused.
+#[derive(Debug, Clone)]
+pub struct PreparedPubkey {
+    pub point: TwistedExtendedPoint,
+    pub table: Vec<TwistedProjectedNiels>,
+    pub torsion: Option<u32>,
+}
+
+pub fn prepare_pubkey(pubkey: &[u8]) -> Result<PreparedPubkey, LibgoldilockErrors> {
+    let point = decode_pubkey(pubkey)?;
+    let torsion = torsion_index(pubkey)?;
+    Ok(PreparedPubkey {
+        table: pubkey_wnaf_table(&point),
+        point,
+        torsion: Some(torsion),
+    })
+}
 
-    r_point = r_point.point_scalar_mul(&scalar_four);
+pub fn dsa_verify_prepared(
+    pubkey: &[u8],
+    prepared: &PreparedPubkey,
+    sig: &[u8],
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
+    policy: &VerificationPolicy,
+) -> Result<bool, LibgoldilockErrors> {
+    check_context(context)?;
+    check_signature_length(sig)?;
 
     let mut challenge: [u8; 114] = [0; 114];
-    let mut h = sig1.to_vec();
-    h.append(&mut p.eddsa_like_encode().to_vec());
-    h.append(&mut message.to_vec());
-    hash_with_dom(&mut h, &mut challenge);
+    hash_with_dom(
+        &[&sig[..57], &pubkey[..57]],
+        message,
+        &mut challenge,
+        prehashed,
+        context,
+    );
 
-    let mut challenge_scalar = decode_long(&challenge);
-    challenge_scalar = sub(&scalar_zero, &challenge_scalar);
+    verify_with_challenge(pubkey, prepared, sig, &challenge, policy)
+}
 
-    let responce_scalar = decode_long(&sig2);
+// The group equation and policy checks of `dsa_verify_prepared` for an
+// already computed challenge k, given as bytes read little-endian mod L.
+pub fn verify_with_challenge(
+    pubkey: &[u8],
+    prepared: &PreparedPubkey,
+    sig: &[u8],
+    challenge: &[u8],
+    policy: &VerificationPolicy,
+) -> Result<bool, LibgoldilockErrors> {
+    let r_point = decode_signature_point(sig)?;
+    let response = decode_signature_scalar(sig)?;
 
-    let pk = p2.point_double_scalamul_non_secret(&responce_scalar, &challenge_scalar);
-    // println!("{:?}", pk);
-    // println!("{:?}", rPoint);
-    // rPoint.eq(&pk)
+    if policy.reject_small_order {
+        if is_small_order(&prepared.point) {
+            return Err(LibgoldilockErrors::SmallOrderPubkeyError);
+        }
+        if is_small_order(&r_point) {
+            return Err(LibgoldilockErrors::SmallOrderSignatureError);
+        }
+    }
+
+    if !verify_equation_with_table(&prepared.table, &r_point, &response, challenge) {
+        return Ok(false);
+    }
+    if policy.cofactored {
+        return Ok(true);
+    }
+
+    // The cofactored equation holds, so [S]B = R + [k]A holds exactly iff the
+    // torsion components cancel: idx(R) + k * idx(A) = 0 mod 4.
+    let torsion_a = match prepared.torsion {
+        Some(torsion) => torsion,
+        None => torsion_index(pubkey)?,
+    };
+    let k = encode(&decode_long(challenge))[0] as u32 & 3;
+    let torsion = torsion_index(&sig[..57])? + k * torsion_a;
+    Ok(torsion & 3 == 0)
+}
+
+// Checks (public key, signature, message) triples at once: the sum of
+// z_i ([S_i]B - [4]R_i - [4 k_i]A_i) over all triples, with random 128-bit
+// weights z_i, must be the identity; the decoded points already carry the
+// factor 4. Like `VerificationPolicy::RFC8032` the check is cofactored; a
+// false result means at least one signature is bad.
+pub fn dsa_verify_batch<R: RngCore + CryptoRng>(
+    items: &[(&[u8], &[u8], &[u8])],
+    rng: &mut R,
+) -> Result<bool, LibgoldilockErrors> {
+    let scalar_zero: Scalar = [0; 14];
+
+    let mut response_sum = scalar_zero;
+    let mut terms = Vec::with_capacity(2 * items.len());
+    for (pubkey, sig, message) in items {
+        let p = decode_pubkey(pubkey)?;
+        let r_point = decode_signature_point(sig)?;
+        let response = decode_signature_scalar(sig)?;
+
+        let mut challenge: [u8; 114] = [0; 114];
+        hash_with_dom(
+            &[&sig[..57], &pubkey[..57]],
+            &[message],
+            &mut challenge,
+            false,
+            &[],
+        );
+
+        let mut weight_bytes: [u8; 16] = [0; 16];
+        rng.fill_bytes(&mut weight_bytes);
+        let weight = decode_short(&weight_bytes, weight_bytes.len());
+
+        response_sum = scalar::add(&response_sum, &scalar::mul(&weight, &response));
+        let challenge_weight = scalar::mul(&weight, &decode_long(&challenge));
+        terms.push((p, sub(&scalar_zero, &challenge_weight)));
+        terms.push((r_point, sub(&scalar_zero, &weight)));
+    }
 
-    Ok(r_point.mod_equal(&pk))
+    Ok(multi_scalarmul_non_secret(&response_sum, &terms).is_identity())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bignumber::{dsa_like_serialize, low_bit};
+    use crate::extended_point::eddsa_like_decode;
+    use crate::goldilocks::{
+        ed448_derive_public, expand_seed, nonce_from_hash, signature_from_challenge,
+    };
 
     #[test]
     pub fn test_dsa_verify() {
@@ -125,7 +457,286 @@ mod tests {
             128,
         ];
         let fox = b"The quick brown fox jumps over the lazy dog";
-        let result = dsa_verify(&public, &sig, fox);
-        assert_eq!(result.unwrap(), true);
+        let result = dsa_verify(&public, &sig, &[fox], false, &[]);
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    pub fn test_dsa_verify_context_too_long() {
+        let context = [0u8; 256];
+        let result = dsa_verify(&[0; 57], &[0; 114], &[], false, &context);
+        assert!(matches!(
+            result,
+            Err(LibgoldilockErrors::ContextTooLongError {
+                max: 255,
+                actual: 256
+            })
+        ));
+    }
+
+    // Applies [i](1, 0) to an encoded point on the untwisted curve.
+    fn add_torsion(encoded: &[u8], i: usize) -> [u8; 57] {
+        let (x, y) = eddsa_like_decompress(encoded).unwrap();
+        let (x, y) = match i % 4 {
+            0 => (x, y),
+            1 => (y, neg(&x)),
+            2 => (neg(&x), neg(&y)),
+            _ => (neg(&y), x),
+        };
+        let mut out: [u8; 57] = [0; 57];
+        out[..56].copy_from_slice(&dsa_like_serialize(&y));
+        out[56] = (low_bit(&x) as u8) & 0x80;
+        out
+    }
+
+    // Signs `message` for the secret scalar `sec` with an explicit nonce,
+    // hashing `public` as A so that its torsion part can be chosen freely.
+    fn sign_raw(
+        sec: &Scalar,
+        nonce_scalar: &Scalar,
+        nonce_point: &[u8; 57],
+        public: &[u8],
+        message: &[u8],
+    ) -> [u8; 114] {
+        let mut challenge: [u8; 114] = [0; 114];
+        hash_with_dom(
+            &[nonce_point, public],
+            &[message],
+            &mut challenge,
+            false,
+            &[],
+        );
+        signature_from_challenge(&challenge, sec, nonce_scalar, nonce_point)
+    }
+
+    fn honest_key() -> (Scalar, [u8; 57], Scalar, [u8; 57]) {
+        let seed = [3u8; 57];
+        let (sk, _) = expand_seed(&seed);
+        let (nonce_scalar, nonce_point) = nonce_from_hash(&[5u8; 114]);
+        (
+            decode_long(&sk),
+            ed448_derive_public(&seed),
+            nonce_scalar,
+            nonce_point,
+        )
+    }
+
+    fn verify(
+        public: &[u8],
+        sig: &[u8],
+        message: &[u8],
+        policy: &VerificationPolicy,
+    ) -> Result<bool, LibgoldilockErrors> {
+        dsa_verify_with_policy(public, sig, &[message], false, &[], policy)
+    }
+
+    // The torsion index the slow way: decoding multiplies by 1/4 and so
+    // recovers the prime-order component, which is compared to the point.
+    fn torsion_index_by_decode(encoded: &[u8]) -> u32 {
+        let (x, y) = eddsa_like_decompress(encoded).unwrap();
+        let point = eddsa_like_decode(encoded).unwrap();
+        let (x0, y0) = eddsa_like_decompress(&point.eddsa_like_encode()).unwrap();
+        let candidates = [
+            (x0, y0),
+            (y0, neg(&x0)),
+            (neg(&x0), neg(&y0)),
+            (neg(&y0), x0),
+        ];
+        let i = candidates
+            .iter()
+            .position(|(cx, cy)| decaf_equal(&x, cx) & decaf_equal(&y, cy) == DECAF_TRUE);
+        i.unwrap() as u32
+    }
+
+    #[test]
+    pub fn test_torsion_index() {
+        let (_, public, _, _) = honest_key();
+        for i in 0..4 {
+            let shifted = add_torsion(&public, i);
+            assert_eq!(torsion_index(&shifted).unwrap(), i as u32);
+        }
+
+        // The identity (0, 1) and its torsion shifts.
+        let mut identity: [u8; 57] = [0; 57];
+        identity[0] = 1;
+        for i in 0..4 {
+            let shifted = add_torsion(&identity, i);
+            assert_eq!(torsion_index(&shifted).unwrap(), i as u32);
+        }
+
+        for seed in 0..16u8 {
+            let public = ed448_derive_public(&[seed; 57]);
+            for i in 0..4 {
+                let shifted = add_torsion(&public, i);
+                assert_eq!(torsion_index(&shifted).unwrap(), i as u32);
+                assert_eq!(torsion_index_by_decode(&shifted), i as u32);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_policy_torsion_pubkey() {
+        let (sec, public, nonce_scalar, nonce_point) = honest_key();
+        for i in 1..4 {
+            let shifted = add_torsion(&public, i);
+            for m in 0..8u8 {
+                let sig = sign_raw(&sec, &nonce_scalar, &nonce_point, &shifted, &[m]);
+                let mut challenge: [u8; 114] = [0; 114];
+                hash_with_dom(
+                    &[&nonce_point, &shifted],
+                    &[&[m]],
+                    &mut challenge,
+                    false,
+                    &[],
+                );
+                let k = encode(&decode_long(&challenge))[0] as usize;
+
+                let rfc = verify(&shifted, &sig, &[m], &VerificationPolicy::RFC8032);
+                assert!(rfc.unwrap());
+                let strict = verify(&shifted, &sig, &[m], &VerificationPolicy::STRICT);
+                assert_eq!(strict.unwrap(), (k * i) & 3 == 0);
+
+                let prepared = prepare_pubkey(&shifted).unwrap();
+                let policy = VerificationPolicy::STRICT;
+                let strict =
+                    dsa_verify_prepared(&shifted, &prepared, &sig, &[&[m]], false, &[], &policy);
+                assert_eq!(strict.unwrap(), (k * i) & 3 == 0);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_policy_torsion_r() {
+        let (sec, public, nonce_scalar, nonce_point) = honest_key();
+        let shifted = add_torsion(&nonce_point, 2);
+        let sig = sign_raw(&sec, &nonce_scalar, &shifted, &public, b"msg");
+        assert!(verify(&public, &sig, b"msg", &VerificationPolicy::RFC8032).unwrap());
+        assert!(!verify(&public, &sig, b"msg", &VerificationPolicy::STRICT).unwrap());
+    }
+
+    #[test]
+    pub fn test_policy_small_order() {
+        let (sec, public, nonce_scalar, nonce_point) = honest_key();
+        let zero: Scalar = [0; 14];
+        let mut identity = [0u8; 57];
+        identity[0] = 1;
+
+        // A of small order: [S]B = R holds for S = r.
+        for i in 0..4 {
+            let small = add_torsion(&identity, i);
+            let sig = sign_raw(&zero, &nonce_scalar, &nonce_point, &small, b"msg");
+            assert!(verify(&small, &sig, b"msg", &VerificationPolicy::RFC8032).unwrap());
+            assert!(matches!(
+                verify(&small, &sig, b"msg", &VerificationPolicy::STRICT),
+                Err(LibgoldilockErrors::SmallOrderPubkeyError)
+            ));
+        }
+
+        // R of small order: [S]B = [k]A holds for S = k * s.
+        let small = add_torsion(&identity, 2);
+        let sig = sign_raw(&sec, &zero, &small, &public, b"msg");
+        assert!(verify(&public, &sig, b"msg", &VerificationPolicy::RFC8032).unwrap());
+        assert!(matches!(
+            verify(&public, &sig, b"msg", &VerificationPolicy::STRICT),
+            Err(LibgoldilockErrors::SmallOrderSignatureError)
+        ));
+    }
+
+    #[test]
+    pub fn test_policy_non_canonical() {
+        let (sec, public, nonce_scalar, nonce_point) = honest_key();
+        let mut sig = sign_raw(&sec, &nonce_scalar, &nonce_point, &public, b"msg");
+        assert!(verify(&public, &sig, b"msg", &VerificationPolicy::STRICT).unwrap());
+
+        // S + L and a nonzero final byte are rejected under every policy.
+        let mut high_byte = sig;
+        high_byte[113] = 1;
+        sig = add_group_order_to_scalar(&sig);
+        for policy in [VerificationPolicy::RFC8032, VerificationPolicy::STRICT] {
+            for sig in [&sig, &high_byte] {
+                assert!(matches!(
+                    verify(&public, sig, b"msg", &policy),
+                    Err(LibgoldilockErrors::NonCanonicalScalarError)
+                ));
+            }
+        }
+
+        // The identity encoded with y = p + 1, as public key and as R.
+        let mut large_y = [0u8; 57];
+        large_y[28..56].fill(0xff);
+        let zero: Scalar = [0; 14];
+        let sig = sign_raw(&zero, &nonce_scalar, &nonce_point, &large_y, b"msg");
+        assert!(matches!(
+            verify(&large_y, &sig, b"msg", &VerificationPolicy::RFC8032),
+            Err(LibgoldilockErrors::NonCanonicalPubkeyError)
+        ));
+        let sig = sign_raw(&sec, &zero, &large_y, &public, b"msg");
+        assert!(matches!(
+            verify(&public, &sig, b"msg", &VerificationPolicy::RFC8032),
+            Err(LibgoldilockErrors::NonCanonicalSignatureError)
+        ));
+    }
+
+    #[test]
+    pub fn test_validate_pubkey() {
+        let (_, public, _, _) = honest_key();
+        let point = decode_pubkey(&public).unwrap();
+        assert!(validate_pubkey(&public).is_ok());
+        assert!(!is_small_order(&point));
+        assert!(is_torsion_free(&public).unwrap());
+
+        let mut identity = [0u8; 57];
+        identity[0] = 1;
+        for i in 0..4 {
+            let shifted = add_torsion(&public, i);
+            let small = add_torsion(&identity, i);
+            assert_eq!(is_torsion_free(&shifted).unwrap(), i == 0);
+            assert!(is_small_order(&eddsa_like_decode(&small).unwrap()));
+            assert!(matches!(
+                validate_pubkey(&small),
+                Err(LibgoldilockErrors::SmallOrderPubkeyError)
+            ));
+            if i != 0 {
+                assert!(matches!(
+                    validate_pubkey(&shifted),
+                    Err(LibgoldilockErrors::TorsionPubkeyError)
+                ));
+            }
+        }
+
+        let mut reserved = public;
+        reserved[56] |= 0x01;
+        assert!(matches!(
+            validate_pubkey(&reserved),
+            Err(LibgoldilockErrors::NonCanonicalPubkeyError)
+        ));
+        let mut off_curve = [0u8; 57];
+        off_curve[0] = 2;
+        assert!(matches!(
+            validate_pubkey(&off_curve),
+            Err(LibgoldilockErrors::DecodePubkeyError)
+        ));
+        assert!(matches!(
+            validate_pubkey(&public[..56]),
+            Err(LibgoldilockErrors::InvalidPubkeyLengthError { .. })
+        ));
+    }
+
+    #[test]
+    pub fn test_dsa_verify_batch() {
+        let mut rng = rand::thread_rng();
+        let (sec, public, nonce_scalar, nonce_point) = honest_key();
+        let honest = sign_raw(&sec, &nonce_scalar, &nonce_point, &public, b"one");
+        // Accepted by the cofactored equation, like single verification.
+        let shifted = add_torsion(&nonce_point, 2);
+        let torsion = sign_raw(&sec, &nonce_scalar, &shifted, &public, b"two");
+
+        let items: [(&[u8], &[u8], &[u8]); 2] =
+            [(&public, &honest, b"one"), (&public, &torsion, b"two")];
+        assert!(dsa_verify_batch(&items, &mut rng).unwrap());
+
+        let items: [(&[u8], &[u8], &[u8]); 2] =
+            [(&public, &honest, b"one"), (&public, &torsion, b"one")];
+        assert!(!dsa_verify_batch(&items, &mut rng).unwrap());
     }
 }
@@ -0,0 +1,330 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use zeroize::Zeroize;
+
+use crate::bignumber::{
+    conditional_negate, conditional_swap, decaf_equal, deserialize, dsa_like_serialize, low_bit,
+};
+use crate::constants32::{BigNumber, Word, DECAF_TRUE};
+use crate::eddsa::torsion_index;
+use crate::errors::LibgoldilockErrors;
+use crate::extended_point::{eddsa_like_decode, precomputed_scalar_mul, TwistedExtendedPoint};
+use crate::scalar::halve;
+use crate::Scalar;
+
+/// A point on the untwisted Ed448 curve x^2 + y^2 = 1 + d x^2 y^2 of
+/// RFC 8032.
+///
+/// The point is held as its prime-order component, in the twisted
+/// representation used for signing, and the index i of its torsion
+/// component [i](1, 0). Decoding for signing drops the torsion component,
+/// so keeping the index is what lets decompressing and compressing any
+/// valid encoding give back the same bytes, while all the curve arithmetic
+/// stays in `TwistedExtendedPoint`.
+#[derive(Clone)]
+pub struct EdwardsPoint {
+    point: TwistedExtendedPoint,
+    torsion: u32,
+}
+
+impl EdwardsPoint {
+    /// The neutral element (0, 1).
+    pub fn identity() -> EdwardsPoint {
+        let mut point = TwistedExtendedPoint::new();
+        point.set_identity();
+        EdwardsPoint { point, torsion: 0 }
+    }
+
+    /// The RFC 8032 base point B, which generates the subgroup of order L.
+    pub fn generator() -> EdwardsPoint {
+        EdwardsPoint::mul_base(&Scalar::ONE)
+    }
+
+    /// [s]B, using the precomputed table.
+    pub fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+        // The comb multiplies by four times its scalar.
+        let mut quarter = halve(halve(scalar.0));
+        let point = precomputed_scalar_mul(quarter);
+
+        quarter.zeroize();
+        EdwardsPoint { point, torsion: 0 }
+    }
+
+    /// Decodes a 57-byte RFC 8032 point encoding. Non-canonical encodings
+    /// are rejected.
+    pub fn decompress(bytes: &[u8; 57]) -> Result<EdwardsPoint, LibgoldilockErrors> {
+        Ok(EdwardsPoint {
+            point: eddsa_like_decode(bytes)?,
+            torsion: torsion_index(bytes)?,
+        })
+    }
+
+    /// The 57-byte RFC 8032 encoding. For points in the prime-order
+    /// subgroup it matches the encoding produced when signing.
+    pub fn compress(&self) -> [u8; 57] {
+        let (x, y) = self.affine_coordinates();
+        let mut out: [u8; 57] = [0; 57];
+        out[..56].copy_from_slice(&dsa_like_serialize(&y));
+        out[56] = (low_bit(&x) as u8) & 0x80;
+        out
+    }
+
+    /// Builds a point from canonical 56-byte little-endian affine
+    /// coordinates, checking that it lies on the curve.
+    pub fn from_affine(x: &[u8; 56], y: &[u8; 56]) -> Result<EdwardsPoint, LibgoldilockErrors> {
+        let (x_value, x_ok) = deserialize(*x);
+        let (_, y_ok) = deserialize(*y);
+        if !x_ok || !y_ok {
+            return Err(LibgoldilockErrors::NonCanonicalEncodingError);
+        }
+
+        // Decompressing y with the sign of x recovers x when (x, y) is on
+        // the curve.
+        let mut encoded: [u8; 57] = [0; 57];
+        encoded[..56].copy_from_slice(y);
+        encoded[56] = (low_bit(&x_value) as u8) & 0x80;
+        let p = EdwardsPoint::decompress(&encoded)?;
+        if &p.to_affine().0 != x {
+            return Err(LibgoldilockErrors::DecodeError);
+        }
+
+        Ok(p)
+    }
+
+    /// The affine coordinates (x, y) as canonical 56-byte little-endian
+    /// integers.
+    pub fn to_affine(&self) -> ([u8; 56], [u8; 56]) {
+        let (x, y) = self.affine_coordinates();
+        (dsa_like_serialize(&x), dsa_like_serialize(&y))
+    }
+
+    /// [4]P, which clears any torsion component.
+    pub fn mul_by_cofactor(&self) -> EdwardsPoint {
+        let mut point = self.point.clone();
+        point.double_internal(false);
+        point.double_internal(false);
+        EdwardsPoint { point, torsion: 0 }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == EdwardsPoint::identity()
+    }
+
+    // Adds [i](1, 0) to the prime-order component. Adding (1, 0) maps
+    // (x, y) to (y, -x), and adding (0, -1) negates both coordinates.
+    fn affine_coordinates(&self) -> (BigNumber, BigNumber) {
+        let (mut x, mut y) = self.point.eddsa_like_affine();
+
+        let odd = (self.torsion & 1).wrapping_neg() as Word;
+        conditional_swap(&mut x, &mut y, &odd);
+        y = conditional_negate(&y, &odd);
+
+        let half = ((self.torsion >> 1) & 1).wrapping_neg() as Word;
+        (conditional_negate(&x, &half), conditional_negate(&y, &half))
+    }
+}
+
+impl Default for EdwardsPoint {
+    fn default() -> Self {
+        EdwardsPoint::identity()
+    }
+}
+
+impl PartialEq for EdwardsPoint {
+    fn eq(&self, other: &Self) -> bool {
+        let (x1, y1) = self.affine_coordinates();
+        let (x2, y2) = other.affine_coordinates();
+        (decaf_equal(&x1, &x2) & decaf_equal(&y1, &y2)) == DECAF_TRUE
+    }
+}
+
+impl Eq for EdwardsPoint {}
+
+impl fmt::Debug for EdwardsPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EdwardsPoint({})", hex::encode(self.compress()))
+    }
+}
+
+impl Add<&EdwardsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn add(self, other: &EdwardsPoint) -> EdwardsPoint {
+        EdwardsPoint {
+            point: self.point.add(&other.point),
+            torsion: (self.torsion + other.torsion) & 3,
+        }
+    }
+}
+
+impl Add for EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn add(self, other: EdwardsPoint) -> EdwardsPoint {
+        &self + &other
+    }
+}
+
+impl Sub<&EdwardsPoint> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn sub(self, other: &EdwardsPoint) -> EdwardsPoint {
+        self + &(-other)
+    }
+}
+
+impl Sub for EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn sub(self, other: EdwardsPoint) -> EdwardsPoint {
+        &self - &other
+    }
+}
+
+impl Neg for &EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn neg(self) -> EdwardsPoint {
+        EdwardsPoint {
+            point: self.point.negate(),
+            torsion: self.torsion.wrapping_neg() & 3,
+        }
+    }
+}
+
+impl Neg for EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn neg(self) -> EdwardsPoint {
+        -&self
+    }
+}
+
+// The prime-order component goes through the windowed multiplication. The
+// torsion index is multiplied by the canonical scalar, which is below L,
+// so only its residue mod 4 matters.
+impl Mul<&Scalar> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn mul(self, scalar: &Scalar) -> EdwardsPoint {
+        EdwardsPoint {
+            point: self.point.point_scalar_mul(&scalar.0),
+            torsion: (self.torsion * (scalar.0[0] & 3)) & 3,
+        }
+    }
+}
+
+impl Mul<Scalar> for EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn mul(self, scalar: Scalar) -> EdwardsPoint {
+        &self * &scalar
+    }
+}
+
+impl Mul<EdwardsPoint> for Scalar {
+    type Output = EdwardsPoint;
+
+    fn mul(self, point: EdwardsPoint) -> EdwardsPoint {
+        &point * &self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The point of order 2, (0, -1), and a point of order 4, (1, 0).
+    fn torsion_points() -> [EdwardsPoint; 2] {
+        let mut one: [u8; 56] = [0; 56];
+        one[0] = 1;
+        let mut minus_one: [u8; 56] = [0xff; 56];
+        minus_one[0] = 0xfe;
+        minus_one[28] = 0xfe;
+        [
+            EdwardsPoint::from_affine(&[0; 56], &minus_one).unwrap(),
+            EdwardsPoint::from_affine(&one, &[0; 56]).unwrap(),
+        ]
+    }
+
+    #[test]
+    pub fn test_generator() {
+        let g = EdwardsPoint::generator();
+        assert_eq!(
+            hex::encode(g.compress()),
+            "14fa30f25b790898adc8d74e2c13bdfdc4397ce61cffd33ad7c2a0051e9c78874098a36c7373ea4b62c7c9563720768824bcb66e71463f6900"
+        );
+        assert_eq!(EdwardsPoint::decompress(&g.compress()).unwrap(), g);
+
+        let (x, y) = g.to_affine();
+        assert_eq!(EdwardsPoint::from_affine(&x, &y).unwrap(), g);
+        assert!(matches!(
+            EdwardsPoint::from_affine(&[0; 56], &[0; 56]),
+            Err(LibgoldilockErrors::DecodeError)
+        ));
+        assert!(matches!(
+            EdwardsPoint::from_affine(&[0xff; 56], &y),
+            Err(LibgoldilockErrors::NonCanonicalEncodingError)
+        ));
+    }
+
+    #[test]
+    pub fn test_group_laws() {
+        let g = EdwardsPoint::generator();
+        let id = EdwardsPoint::identity();
+        let a = Scalar::from(0x1234_5678_9abc_def0);
+        let b = Scalar::from(0x0fed_cba9_8765_4321);
+
+        assert!(id.is_identity());
+        assert_eq!(&g + &id, g);
+        assert!((&g - &g).is_identity());
+        assert_eq!(-(-&g), g);
+        assert_eq!(&g * &Scalar::ZERO, id);
+        assert_eq!(&g * &Scalar::ONE, g);
+        assert_eq!(&g * &Scalar::from(3), &(&g + &g) + &g);
+        assert_eq!(&(&g * &a) + &(&g * &b), &g * &(a + b));
+        assert_eq!(&(&g * &a) * &b, &(&g * &b) * &a);
+        assert_eq!(a * g.clone(), g.clone() * a);
+        assert_eq!(EdwardsPoint::mul_base(&a), &g * &a);
+
+        // L - 1 times B is -B.
+        assert_eq!(&g * &-Scalar::ONE, -&g);
+    }
+
+    #[test]
+    pub fn test_matches_internal_encoding() {
+        let s = Scalar::from_bytes_mod_order(&[0x5a; 57]);
+        let p = &EdwardsPoint::generator() * &s;
+        assert_eq!(p.compress(), EdwardsPoint::mul_base(&s).compress());
+
+        let decoded = eddsa_like_decode(&p.compress()).unwrap();
+        assert_eq!(decoded.eddsa_like_encode(), p.compress());
+    }
+
+    #[test]
+    pub fn test_torsion() {
+        let g = EdwardsPoint::generator();
+        let s = Scalar::from(7);
+        for t in torsion_points() {
+            assert!(!t.is_identity());
+            assert!(t.mul_by_cofactor().is_identity());
+
+            // The torsion component survives a round trip through the
+            // encoding, and is cleared by the cofactor.
+            let p = &g + &t;
+            let decoded = EdwardsPoint::decompress(&p.compress()).unwrap();
+            assert_eq!(decoded, p);
+            assert_ne!(decoded, g);
+            assert_eq!(p.mul_by_cofactor(), g.mul_by_cofactor());
+            assert_eq!(g.mul_by_cofactor(), &g * &Scalar::from(4));
+
+            // Torsion components add and scale with the point.
+            assert_eq!(&p * &s, &(&g * &s) + &(&t * &s));
+            assert_eq!(&(&p - &t), &g);
+            assert!((&t + &(-&t)).is_identity());
+            let (x, y) = p.to_affine();
+            assert_eq!(EdwardsPoint::from_affine(&x, &y).unwrap(), p);
+        }
+    }
+}
@@ -1,21 +1,139 @@
-use std::fmt;
 use std::error::Error;
+use std::fmt;
 
 #[derive(Debug)]
 pub enum LibgoldilockErrors {
     DecodeError,
     DecodePubkeyError,
     DecodeSignatureError,
-    InvalidLengthError,
-    InvalidPubkeyLengthError,
-    InvalidSignatureLengthError,
+    InvalidLengthError { expected: usize, actual: usize },
+    InvalidPubkeyLengthError { expected: usize, actual: usize },
+    InvalidSignatureLengthError { expected: usize, actual: usize },
     InvalidSignatureError,
+    ContextTooLongError { max: usize, actual: usize },
+    InvalidPrehashLengthError { expected: usize, actual: usize },
+    NonCanonicalPubkeyError,
+    NonCanonicalSignatureError,
+    SmallOrderPubkeyError,
+    SmallOrderSignatureError,
+    NonCanonicalScalarError,
+    NonCanonicalEncodingError,
+    TorsionPubkeyError,
+    BatchVerificationError(Vec<usize>),
+    FaultDetectedError,
+    PubkeyMismatchError,
+    MessageChangedError,
+    HexDecodeError(hex::FromHexError),
+    IoError(std::io::Error),
 }
 
 impl fmt::Display for LibgoldilockErrors {
-    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> Result<(), std::fmt::Error> { 
-        todo!() 
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            LibgoldilockErrors::DecodeError => write!(f, "point is not on the curve"),
+            LibgoldilockErrors::DecodePubkeyError => write!(f, "public key is not on the curve"),
+            LibgoldilockErrors::DecodeSignatureError => {
+                write!(f, "signature R is not on the curve")
+            }
+            LibgoldilockErrors::InvalidLengthError { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            LibgoldilockErrors::InvalidPubkeyLengthError { expected, actual } => write!(
+                f,
+                "invalid public key length: expected {expected} bytes, got {actual}"
+            ),
+            LibgoldilockErrors::InvalidSignatureLengthError { expected, actual } => write!(
+                f,
+                "invalid signature length: expected {expected} bytes, got {actual}"
+            ),
+            LibgoldilockErrors::InvalidSignatureError => write!(f, "signature does not verify"),
+            LibgoldilockErrors::ContextTooLongError { max, actual } => write!(
+                f,
+                "context too long: at most {max} bytes allowed, got {actual}"
+            ),
+            LibgoldilockErrors::InvalidPrehashLengthError { expected, actual } => write!(
+                f,
+                "invalid prehash length: expected {expected} bytes, got {actual}"
+            ),
+            LibgoldilockErrors::NonCanonicalPubkeyError => {
+                write!(f, "public key is not canonically encoded")
+            }
+            LibgoldilockErrors::NonCanonicalSignatureError => {
+                write!(f, "signature R is not canonically encoded")
+            }
+            LibgoldilockErrors::SmallOrderPubkeyError => {
+                write!(f, "public key has small order")
+            }
+            LibgoldilockErrors::SmallOrderSignatureError => {
+                write!(f, "signature R has small order")
+            }
+            LibgoldilockErrors::NonCanonicalScalarError => {
+                write!(f, "scalar is not below the group order")
+            }
+            LibgoldilockErrors::NonCanonicalEncodingError => {
+                write!(f, "point is not canonically encoded")
+            }
+            LibgoldilockErrors::TorsionPubkeyError => {
+                write!(f, "public key has a torsion component")
+            }
+            LibgoldilockErrors::BatchVerificationError(invalid) => {
+                write!(f, "batch verification failed for items {invalid:?}")
+            }
+            LibgoldilockErrors::FaultDetectedError => {
+                write!(f, "fault detected while signing")
+            }
+            LibgoldilockErrors::PubkeyMismatchError => {
+                write!(f, "embedded public key is not the expected key")
+            }
+            LibgoldilockErrors::MessageChangedError => {
+                write!(f, "message changed between the two passes over the reader")
+            }
+            LibgoldilockErrors::HexDecodeError(err) => write!(f, "invalid hex: {err}"),
+            LibgoldilockErrors::IoError(err) => write!(f, "I/O error: {err}"),
+        }
+    }
+}
+
+impl Error for LibgoldilockErrors {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LibgoldilockErrors::HexDecodeError(err) => Some(err),
+            LibgoldilockErrors::IoError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for LibgoldilockErrors {
+    fn from(err: std::io::Error) -> Self {
+        LibgoldilockErrors::IoError(err)
     }
 }
 
-impl Error for LibgoldilockErrors {}
\ No newline at end of file
+impl From<hex::FromHexError> for LibgoldilockErrors {
+    fn from(err: hex::FromHexError) -> Self {
+        LibgoldilockErrors::HexDecodeError(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_display() {
+        let err = LibgoldilockErrors::InvalidSignatureLengthError {
+            expected: 114,
+            actual: 57,
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid signature length: expected 114 bytes, got 57"
+        );
+        assert!(err.source().is_none());
+
+        let err = LibgoldilockErrors::from(hex::FromHexError::OddLength);
+        assert!(err.to_string().starts_with("invalid hex: "));
+        assert!(err.source().is_some());
+    }
+}
@@ -1,16 +1,21 @@
+use zeroize::Zeroize;
+
 use crate::errors::LibgoldilockErrors;
 use crate::{
     bignumber::*,
     constants32::{
-        BigNumber, Dword, Sword, Word, BIG_ONE, BIG_ZERO, DECAF_COMB_NUMBER, DECAF_COMB_SPACING,
-        DECAF_COMB_TEETH, DECAF_TRUE, EDWARDS_D, FIELD_BYTES, SCALAR_BITS, SCALAR_WORDS, WORD_BITS,
-        ZERO_MASK,
+        BigNumber, Dword, Sword, Word, BIG_MINUS_D, BIG_ONE, BIG_ZERO, DECAF_COMB_NUMBER,
+        DECAF_COMB_SPACING, DECAF_COMB_TEETH, DECAF_TRUE, EDWARDS_D, FIELD_BYTES, SCALAR_BITS,
+        SCALAR_WORDS, WORD_BITS, ZERO_MASK,
     },
     decaf_combs_32::DECAF_PRECOMP_TABLE,
     decaf_wnaf_table::DECAF_WNAF_TABLE,
     scalar::{self, halve, Scalar},
 };
 
+const WNAF_VAR_TABLE_BITS: usize = 3; // DECAF_WNAF_VAR_TABLE_BITS
+const WNAF_FIXED_TABLE_BITS: usize = 5; // DECAF_WNAF_FIXED_TABLE_BITS
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct SmvtControl {
     power: i64,
@@ -120,6 +125,10 @@ impl TwistedExtendedPoint {
         }
     }
 
+    pub fn is_identity(&self) -> bool {
+        decaf_equal(&self.x, &BIG_ZERO) == DECAF_TRUE
+    }
+
     pub fn mod_equal(&self, p2: &TwistedExtendedPoint) -> bool {
         let a = mul(&self.y, &p2.x);
         let b = mul(&self.x, &p2.y);
@@ -185,9 +194,23 @@ impl TwistedExtendedPoint {
     }
 
     pub fn eddsa_like_encode(&self) -> [u8; 57] {
+        let (mut x, mut y) = self.eddsa_like_affine();
+
+        let mut res: [u8; 57] = [0; 57];
+        res[0..56].copy_from_slice(&dsa_like_serialize(&y));
+        res[56] = (ZERO_MASK & low_bit(&x)) as u8;
+
+        x.zeroize();
+        y.zeroize();
+        res
+    }
+
+    // The affine (x, y) on the untwisted curve that `eddsa_like_encode`
+    // serializes.
+    pub fn eddsa_like_affine(&self) -> (BigNumber, BigNumber) {
         let mut x = square(&self.x);
         let mut t = square(&self.y);
-        let u = add(&x, &t);
+        let mut u = add(&x, &t);
         let mut z = add(&self.y, &self.x);
         let mut y = square(&z);
         y = sub(&u, &y);
@@ -198,16 +221,14 @@ impl TwistedExtendedPoint {
         x = mul(&t, &y);
         y = mul(&z, &u);
         z = mul(&u, &t);
-        // must zero out temporary variables
         z = invert(&z);
         t = mul(&x, &z);
         x = mul(&y, &z);
 
-        let mut res: [u8; 57] = [0; 57];
-        res[0..56].copy_from_slice(&dsa_like_serialize(&x));
-        res[56] = (ZERO_MASK & low_bit(&t)) as u8;
-
-        res
+        for temp in [&mut y, &mut z, &mut u] {
+            temp.zeroize();
+        }
+        (strong_reduce(t), strong_reduce(x))
     }
 
     pub fn is_on_curve(&self) -> bool {
@@ -300,105 +321,185 @@ impl TwistedExtendedPoint {
 
             i -= window as i32;
         }
+
+        scalar1x.zeroize();
         out
     }
 
+    // The variable-base wNAF table of this point, as used by
+    // `point_double_scalamul_non_secret`.
+    pub fn prepare_wnaf_table(&self) -> Vec<TwistedProjectedNiels> {
+        let mut p = self.clone();
+        let mut precmp_var = vec![TwistedProjectedNiels::new(); 1 << WNAF_VAR_TABLE_BITS];
+        decaf_prepare_wnaf_table(&mut precmp_var, &mut p, WNAF_VAR_TABLE_BITS);
+        precmp_var
+    }
+
     pub fn point_double_scalamul_non_secret(
         &self,
         scalar_pre: &Scalar,
         scalar_var: &Scalar,
     ) -> TwistedExtendedPoint {
-        let mut p = self.clone();
-
-        let table_bits_var: usize = 3; // DECAF_WNAF_VAR_TABLE_BITS
-        let table_bits_pre: usize = 5; // DECAF_WNAF_FIXED_TABLE_BITS
-
-        let mut control_var = Vec::new();
-        for _i in 0..115 {
-            control_var.push(SmvtControl::new());
-        }
-
-        let mut control_pre = Vec::new();
-        for _i in 0..77 {
-            control_pre.push(SmvtControl::new());
-        }
+        double_scalarmul_non_secret_with_table(&self.prepare_wnaf_table(), scalar_pre, scalar_var)
+    }
+}
 
-        recode_wnaf(&mut control_pre, scalar_pre, table_bits_pre);
-        recode_wnaf(&mut control_var, scalar_var, table_bits_var);
+// [scalar_pre]B + [scalar_var]P for public scalars, where `precmp_var` is the
+// table `P.prepare_wnaf_table()`.
+pub fn double_scalarmul_non_secret_with_table(
+    precmp_var: &[TwistedProjectedNiels],
+    scalar_pre: &Scalar,
+    scalar_var: &Scalar,
+) -> TwistedExtendedPoint {
+    let table_bits_var: usize = WNAF_VAR_TABLE_BITS;
+    let table_bits_pre: usize = WNAF_FIXED_TABLE_BITS;
+
+    let mut control_var = Vec::new();
+    for _i in 0..115 {
+        control_var.push(SmvtControl::new());
+    }
 
-        let mut precmp_var = Vec::new();
-        for _i in 0..8 {
-            precmp_var.push(TwistedProjectedNiels::new());
-        }
+    let mut control_pre = Vec::new();
+    for _i in 0..77 {
+        control_pre.push(SmvtControl::new());
+    }
 
-        decaf_prepare_wnaf_table(&mut precmp_var, &mut p, table_bits_var);
+    recode_wnaf(&mut control_pre, scalar_pre, table_bits_pre);
+    recode_wnaf(&mut control_var, scalar_var, table_bits_var);
+
+    let mut contp: usize = 0;
+    let mut contv: usize = 0;
+
+    let index = control_var[0].addend >> 1;
+
+    let mut i = control_var[0].power;
+    let mut out;
+
+    if i > control_pre[0].power {
+        out = precmp_var[index as usize].to_extended_point();
+        contv += 1;
+    } else if i == control_pre[0].power && i >= 0 {
+        out = precmp_var[index as usize].to_extended_point();
+        out.add_niels_to_extended(
+            &DECAF_WNAF_TABLE[(control_pre[0].addend as usize) >> 1],
+            i != 0,
+        );
+        contv += 1;
+        contp += 1;
+    } else {
+        i = control_pre[0].power;
+        out = DECAF_WNAF_TABLE[(control_pre[0].addend as usize) >> 1].to_extended();
+        contp += 1;
+    }
 
-        let mut contp: usize = 0;
-        let mut contv: usize = 0;
+    if i < 0 {
+        out.set_identity();
+        return out;
+    }
 
-        let index = control_var[0].addend >> 1;
+    i -= 1;
+    while i >= 0 {
+        let cv = i == control_var[contv].power;
+        let cp = i == control_pre[contp].power;
 
-        let mut i = control_var[0].power;
-        let mut out;
+        out.double_internal(i != 0 && !(cv || cp));
 
-        if i > control_pre[0].power {
-            out = precmp_var[index as usize].to_extended_point();
-            contv += 1;
-        } else if i == control_pre[0].power && i >= 0 {
-            out = precmp_var[index as usize].to_extended_point();
-            out.add_niels_to_extended(
-                &DECAF_WNAF_TABLE[(control_pre[0].addend as usize) >> 1],
-                i != 0,
-            );
+        if cv {
+            if control_var[contv].addend > 0 {
+                let a = control_var[contv].addend as usize;
+                out.add_projected_niels_to_extended(&precmp_var[a >> 1], i != 0 && !cp);
+            } else {
+                let a = (-control_var[contv].addend) as usize;
+                out.sub_projected_niels_from_extended_point(&precmp_var[a >> 1], i != 0 && !cp);
+            }
             contv += 1;
-            contp += 1;
-        } else {
-            i = control_pre[0].power;
-            out = DECAF_WNAF_TABLE[(control_pre[0].addend as usize) >> 1].to_extended();
-            contp += 1;
         }
 
-        if i < 0 {
-            out.set_identity();
-            return out;
+        if cp {
+            if control_pre[contp].addend > 0 {
+                let a = control_pre[contp].addend as usize;
+                out.add_niels_to_extended(&DECAF_WNAF_TABLE[a >> 1], i != 0)
+            } else {
+                let a = (-control_pre[contp].addend) as usize;
+                out.sub_niels_from_extended_point(&DECAF_WNAF_TABLE[a >> 1], i != 0)
+            }
+            contp += 1;
         }
 
         i -= 1;
-        while i >= 0 {
-            let cv = i == control_var[contv].power;
-            let cp = i == control_pre[contp].power;
-
-            out.double_internal(i != 0 && !(cv || cp));
-
-            if cv {
-                if control_var[contv].addend > 0 {
-                    let a = control_var[contv].addend as usize;
-                    out.add_projected_niels_to_extended(&precmp_var[a >> 1], i != 0 && !cp);
-                } else {
-                    let a = (-control_var[contv].addend) as usize;
-                    out.sub_projected_niels_from_extended_point(&precmp_var[a >> 1], i != 0 && !cp);
-                }
-                contv += 1;
-            }
+    }
 
-            if cp {
-                if control_pre[contp].addend > 0 {
-                    let a = control_pre[contp].addend as usize;
-                    out.add_niels_to_extended(&DECAF_WNAF_TABLE[a >> 1], i != 0)
-                } else {
-                    let a = (-control_pre[contp].addend) as usize;
-                    out.sub_niels_from_extended_point(&DECAF_WNAF_TABLE[a >> 1], i != 0)
-                }
-                contp += 1;
-            }
+    out
+}
+
+// [scalar_pre]B + sum [s_i]P_i for public scalars. All wNAF expansions share
+// one run of doublings, so this costs little more than a single
+// `point_double_scalamul_non_secret` per extra term.
+pub fn multi_scalarmul_non_secret(
+    scalar_pre: &Scalar,
+    terms: &[(TwistedExtendedPoint, Scalar)],
+) -> TwistedExtendedPoint {
+    let table_bits_var: usize = WNAF_VAR_TABLE_BITS;
+    let table_bits_pre: usize = WNAF_FIXED_TABLE_BITS;
+
+    let mut control_pre = vec![SmvtControl::new(); 77];
+    recode_wnaf(&mut control_pre, scalar_pre, table_bits_pre);
+
+    let mut control_var = Vec::with_capacity(terms.len());
+    let mut precmp_var = Vec::with_capacity(terms.len());
+    for (point, s) in terms {
+        let mut control = vec![SmvtControl::new(); 115];
+        recode_wnaf(&mut control, s, table_bits_var);
+        control_var.push(control);
+
+        precmp_var.push(point.prepare_wnaf_table());
+    }
 
-            i -= 1;
+    let mut i = control_var
+        .iter()
+        .map(|control| control[0].power)
+        .fold(control_pre[0].power, i64::max);
+
+    let mut contp: usize = 0;
+    let mut contv = vec![0; terms.len()];
+    let mut out = TwistedExtendedPoint::new();
+    out.set_identity();
+
+    while i >= 0 {
+        out.double_internal(false);
+
+        for (j, control) in control_var.iter().enumerate() {
+            let c = &control[contv[j]];
+            if c.power != i {
+                continue;
+            }
+            if c.addend > 0 {
+                out.add_projected_niels_to_extended(
+                    &precmp_var[j][(c.addend as usize) >> 1],
+                    false,
+                );
+            } else {
+                let a = (-c.addend) as usize;
+                out.sub_projected_niels_from_extended_point(&precmp_var[j][a >> 1], false);
+            }
+            contv[j] += 1;
         }
 
-        out
+        let c = &control_pre[contp];
+        if c.power == i {
+            if c.addend > 0 {
+                out.add_niels_to_extended(&DECAF_WNAF_TABLE[(c.addend as usize) >> 1], false);
+            } else {
+                let a = (-c.addend) as usize;
+                out.sub_niels_from_extended_point(&DECAF_WNAF_TABLE[a >> 1], false);
+            }
+            contp += 1;
+        }
 
-        // CONTINUE
+        i -= 1;
     }
+
+    out
 }
 
 impl Default for TwistedNiels {
@@ -507,12 +608,15 @@ pub fn decaf_prepare_wnaf_table(
     });
 }
 
-#[allow(unused_variables)]
-#[allow(unused_assignments)]
-pub fn eddsa_like_decode(src_org: &[u8]) -> Result<TwistedExtendedPoint, LibgoldilockErrors> {
-    let mut p = TwistedExtendedPoint::new();
+// Recovers the affine (x, y) of an encoded point on the untwisted RFC 8032
+// curve, before any mapping into the internal twisted representation.
+// Only canonical encodings are accepted (RFC 8032, section 5.2.3).
+pub fn eddsa_like_decompress(src_org: &[u8]) -> Result<(BigNumber, BigNumber), LibgoldilockErrors> {
     if src_org.len() != 57 {
-        return Err(LibgoldilockErrors::InvalidLengthError);
+        return Err(LibgoldilockErrors::InvalidLengthError {
+            expected: 57,
+            actual: src_org.len(),
+        });
     }
     let mut src: [u8; 57] = [0; 57];
     src.copy_from_slice(src_org);
@@ -520,21 +624,53 @@ pub fn eddsa_like_decode(src_org: &[u8]) -> Result<TwistedExtendedPoint, Libgold
     let low = !is_zero_mask(ZERO_MASK & (src[FIELD_BYTES] as Word));
     src[FIELD_BYTES] &= (!cofactor_mask) as u8;
 
-    // TODO: This is never used
+    // The bits next to the sign bit are reserved and y must be below p.
     let mut succ = is_zero_mask(src[FIELD_BYTES] as Word);
-    let succ1: u32;
-    (p.y, succ1) = dsa_like_deserialize(&src, 0);
-    // TODO: No use after this
+    let (y, succ1) = dsa_like_deserialize(&src, 0);
     succ &= succ1;
+    if succ != DECAF_TRUE {
+        return Err(LibgoldilockErrors::NonCanonicalEncodingError);
+    }
+
+    let y2 = square(&y);
+    let u = sub(&BIG_ONE, &y2);
+    let mut v = mul_with_signed_curve_constant(&y2, &EDWARDS_D);
+    v = sub(&BIG_ONE, &v);
+    let mut x = mul(&u, &v);
+    x = isr(&x);
+    x = mul(&x, &u);
+    x = decaf_cond_negate(&x, &(low_bit(&x) ^ low));
+
+    if decaf_equal(&mul(&square(&x), &v), &u) != DECAF_TRUE {
+        return Err(LibgoldilockErrors::DecodeError);
+    }
+    // x = 0 has no negative, so its sign bit must be clear.
+    if low_bit(&x) != low {
+        return Err(LibgoldilockErrors::NonCanonicalEncodingError);
+    }
 
-    p.x = square(&p.y);
-    p.z = sub(&BIG_ONE, &p.x);
-    p.t = mul_with_signed_curve_constant(&p.x, &EDWARDS_D);
-    p.t = sub(&BIG_ONE, &p.t);
-    p.x = mul(&p.z, &p.t);
-    p.t = isr(&p.x);
-    p.x = mul(&p.t, &p.z);
-    p.x = decaf_cond_negate(&p.x, &(!(low_bit(&p.x)) ^ low));
+    Ok((x, y))
+}
+
+pub fn eddsa_like_decode(src_org: &[u8]) -> Result<TwistedExtendedPoint, LibgoldilockErrors> {
+    let mut scalar_one_forth: Scalar = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    scalar_one_forth = halve(scalar_one_forth);
+    scalar_one_forth = halve(scalar_one_forth);
+
+    Ok(eddsa_like_decode_times_four(src_org)?.point_scalar_mul(&scalar_one_forth))
+}
+
+// The 4-isogeny image of an encoded point, which is [4] times the result of
+// `eddsa_like_decode`. It is torsion free, so verification can use it without
+// the scalar multiplication by 1/4, but `eddsa_like_encode` does not invert it.
+pub fn eddsa_like_decode_times_four(
+    src_org: &[u8],
+) -> Result<TwistedExtendedPoint, LibgoldilockErrors> {
+    let mut p = TwistedExtendedPoint::new();
+    let (x, y) = eddsa_like_decompress(src_org)?;
+    // The isogeny below is written for the opposite sign of x.
+    p.x = neg(&x);
+    p.y = y;
     p.z = BIG_ONE;
 
     let c = square(&p.x);
@@ -557,12 +693,6 @@ pub fn eddsa_like_decode(src_org: &[u8]) -> Result<TwistedExtendedPoint, Libgold
         return Err(LibgoldilockErrors::DecodeError);
     }
 
-    let mut scalar_one_forth: Scalar = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-    scalar_one_forth = halve(scalar_one_forth);
-    scalar_one_forth = halve(scalar_one_forth);
-
-    p = p.point_scalar_mul(&scalar_one_forth);
-
     Ok(p)
 }
 
@@ -601,9 +731,151 @@ pub fn precomputed_scalar_mul(s: Scalar) -> TwistedExtendedPoint {
         }
     }
 
+    scalar2.zeroize();
     p
 }
 
+// RFC 8032 encoding of the Ed448 base point B.
+const ENCODED_BASE_POINT: [u8; 57] = [
+    0x14, 0xfa, 0x30, 0xf2, 0x5b, 0x79, 0x08, 0x98, 0xad, 0xc8, 0xd7, 0x4e, 0x2c, 0x13, 0xbd, 0xfd,
+    0xc4, 0x39, 0x7c, 0xe6, 0x1c, 0xff, 0xd3, 0x3a, 0xd7, 0xc2, 0xa0, 0x05, 0x1e, 0x9c, 0x78, 0x87,
+    0x40, 0x98, 0xa3, 0x6c, 0x73, 0x73, 0xea, 0x4b, 0x62, 0xc7, 0xc9, 0x56, 0x37, 0x20, 0x76, 0x88,
+    0x24, 0xbc, 0xb6, 0x6e, 0x71, 0x46, 0x3f, 0x69, 0x00,
+];
+
+// `precomputed_scalar_mul` computed a second time through an independent
+// path: a fixed-window multiplication of the base point decoded from its
+// encoding, which touches neither the comb table nor its lookups. A fault in
+// either computation shows up as a mismatch.
+pub fn checked_precomputed_scalar_mul(
+    s: Scalar,
+) -> Result<TwistedExtendedPoint, LibgoldilockErrors> {
+    let p = precomputed_scalar_mul(s);
+    let q = eddsa_like_decode_times_four(&ENCODED_BASE_POINT)?.point_scalar_mul(&s);
+    if !p.is_on_curve() || !p.mod_equal(&q) {
+        return Err(LibgoldilockErrors::FaultDetectedError);
+    }
+
+    Ok(p)
+}
+
+// sqrt(-d), the even root, which fixes the sign choices of the Decaf448
+// encoding (libdecaf's RISTRETTO_FACTOR).
+const DECAF_FACTOR: BigNumber = [
+    89597750, 70185204, 13521558, 129985186, 248668211, 256890605, 176396902, 157860171, 77780864,
+    193811638, 27768997, 111807007, 225587106, 109297512, 199959799, 36541999,
+];
+
+impl TwistedExtendedPoint {
+    pub fn add(&self, q: &TwistedExtendedPoint) -> TwistedExtendedPoint {
+        let mut r = self.clone();
+        r.add_projected_niels_to_extended(&q.to_projected_niels(), false);
+        r
+    }
+
+    pub fn negate(&self) -> TwistedExtendedPoint {
+        TwistedExtendedPoint {
+            x: neg(&self.x),
+            y: self.y,
+            z: self.z,
+            t: neg(&self.t),
+        }
+    }
+
+    // The RFC 9496 Decaf448 encoding, computed on the internal twisted
+    // curve as libdecaf does. Points that differ by 4-torsion encode alike.
+    pub fn decaf_encode(&self) -> [u8; FIELD_BYTES] {
+        let num = mul(&add(&self.x, &self.t), &sub(&self.x, &self.t));
+        let mut t1 = mul(&square(&self.x), &num);
+        t1 = mul_with_signed_curve_constant(&t1, &-EDWARDS_D);
+        let inv_sqrt = isr(&t1);
+
+        let mut ratio = mul(&inv_sqrt, &num);
+        ratio = decaf_cond_negate(&ratio, &low_bit(&mul(&ratio, &DECAF_FACTOR)));
+        let mut u = sub(&mul(&ratio, &self.z), &self.t);
+        u = mul_with_signed_curve_constant(&mul(&u, &self.x), &-EDWARDS_D);
+        let mut s = mul(&u, &inv_sqrt);
+        s = decaf_cond_negate(&s, &low_bit(&s));
+
+        dsa_like_serialize(&s)
+    }
+}
+
+pub fn decaf_decode(bytes: &[u8; FIELD_BYTES]) -> Result<TwistedExtendedPoint, LibgoldilockErrors> {
+    // s must be below p and not negative.
+    let (s, canonical) = deserialize_return_mask(*bytes);
+    if (canonical & !low_bit(&s)) != DECAF_TRUE {
+        return Err(LibgoldilockErrors::NonCanonicalEncodingError);
+    }
+
+    let s2 = square(&s);
+    let den = sub(&BIG_ONE, &s2);
+    let y_num = add(&BIG_ONE, &s2);
+    let mut num = mul_with_signed_curve_constant(&s2, &(-4 * (EDWARDS_D - 1)));
+    num = add(&square(&den), &num);
+    let tmp = mul(&num, &square(&den));
+    let inv_sqrt = isr(&tmp);
+    if decaf_equal(&mul(&square(&inv_sqrt), &tmp), &BIG_ONE) != DECAF_TRUE {
+        return Err(LibgoldilockErrors::DecodeError);
+    }
+
+    let den_inv_sqrt = mul(&inv_sqrt, &den);
+    let mut p = TwistedExtendedPoint::new();
+    p.y = mul(&den_inv_sqrt, &y_num);
+    let s_den_inv_sqrt = mul(&den_inv_sqrt, &s);
+    let two_s = add(&s_den_inv_sqrt, &s_den_inv_sqrt);
+    p.x = mul(&mul(&two_s, &inv_sqrt), &num);
+    p.x = decaf_cond_negate(&p.x, &low_bit(&mul(&two_s, &DECAF_FACTOR)));
+    p.z = BIG_ONE;
+    p.t = mul(&p.x, &p.y);
+
+    Ok(p)
+}
+
+// Elligator 2 onto the Jacobi quartic, then the isogeny to the internal
+// curve: the MAP function of RFC 9496, section 5.3.4.
+pub fn decaf_map_to_curve(bytes: &[u8; FIELD_BYTES]) -> TwistedExtendedPoint {
+    let (mut r0, _) = deserialize_return_mask(*bytes);
+    r0 = strong_reduce(r0);
+    let r = neg(&square(&r0));
+
+    // c = (d r + 1 - d)(d r - r - d)
+    let dr = mul_with_signed_curve_constant(&r, &EDWARDS_D);
+    let c = mul(
+        &add(&add(&dr, &BIG_ONE), &BIG_MINUS_D),
+        &add(&sub(&dr, &r), &BIG_MINUS_D),
+    );
+    // n = (r + 1)(1 - 2d)
+    let n = mul_with_signed_curve_constant(&add(&r, &BIG_ONE), &(1 - 2 * EDWARDS_D));
+
+    // e = 1/sqrt(c n) when c n is a square, r0 sqrt(-1/(c n)) otherwise.
+    let cn = mul(&c, &n);
+    let mut e = isr(&cn);
+    let square_mask = decaf_equal(&mul(&square(&e), &cn), &BIG_ONE);
+    e = mul(&e, &constant_time_select(&BIG_ONE, &r0, &square_mask));
+
+    // s = +-|n e|, negative exactly when c n is not a square.
+    let mut s = mul(&n, &e);
+    s = decaf_cond_negate(&s, &(low_bit(&s) ^ !square_mask));
+
+    // t = -+ c n (r - 1) ((1 - 2d) e)^2 - 1
+    let mut t = square(&mul_with_signed_curve_constant(&e, &(1 - 2 * EDWARDS_D)));
+    t = mul(&mul(&t, &sub(&r, &BIG_ONE)), &n);
+    t = decaf_cond_negate(&t, &square_mask);
+    t = sub(&t, &BIG_ONE);
+
+    let s2 = square(&s);
+    let two_s = add(&s, &s);
+    let one_plus_s2 = add(&s2, &BIG_ONE);
+    let one_minus_s2 = sub(&BIG_ONE, &s2);
+    TwistedExtendedPoint {
+        x: mul(&two_s, &t),
+        y: mul(&one_plus_s2, &one_minus_s2),
+        z: mul(&one_minus_s2, &t),
+        t: mul(&two_s, &one_plus_s2),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // use crate::{constants32::{fieldBytes, bigOne, bigZero}};
@@ -880,6 +1152,20 @@ mod tests {
         assert_eq!(p.t, exp.t);
     }
 
+    #[test]
+    pub fn test_checked_precomputed_scalar_mul() {
+        let mut bytes = [0u8; 114];
+        rand::RngCore::fill_bytes(&mut rand::thread_rng(), &mut bytes);
+        let s = scalar::decode_long(&bytes);
+        let p = checked_precomputed_scalar_mul(s).unwrap();
+        assert_eq!(p, precomputed_scalar_mul(s));
+
+        let one = scalar::set(1);
+        let base = eddsa_like_decode_times_four(&ENCODED_BASE_POINT).unwrap();
+        assert!(precomputed_scalar_mul(one).mod_equal(&base));
+        assert!(!p.mod_equal(&base));
+    }
+
     #[test]
     pub fn test_is_valid_point() {
         let mut p = TwistedExtendedPoint::new();
@@ -927,6 +1213,93 @@ mod tests {
         assert_eq!(p.is_on_curve(), false);
     }
 
+    #[test]
+    pub fn test_multi_scalarmul_non_secret() {
+        let a: Scalar = [
+            0x529eec33, 0x6a44d1a6, 0x0b2cbb61, 0x3ea57e6c, 0x61d8d4b8, 0x0b4e7c0c, 0x5b87d2b3,
+            0x19a4ad07, 0x4eac2b11, 0x7c5e8a0d, 0x0e2e04f4, 0x3c6e5f9b, 0x1ea2b5c3, 0x2a6b4e1f,
+        ];
+        let b: Scalar = [
+            0x0bcd1234, 0x74d65764, 0xc0be082e, 0x1d9ac30a, 0x087b8fb9, 0x7f8ed27d, 0xc96a3c35,
+            0xffb823a3, 0x3ff4b2d4, 0x5e07e1c2, 0x2f4ab39d, 0x6d8b0e31, 0x0c1a5e77, 0x1fedcba9,
+        ];
+        let c: Scalar = [7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let zero: Scalar = [0; 14];
+        let p = precomputed_scalar_mul(a);
+
+        let single = p.point_double_scalamul_non_secret(&c, &b);
+        let multi = multi_scalarmul_non_secret(&c, &[(p.clone(), b)]);
+        assert!(single.mod_equal(&multi));
+
+        let single = p.point_double_scalamul_non_secret(&c, &scalar::add(&a, &b));
+        let multi = multi_scalarmul_non_secret(&c, &[(p.clone(), b), (p.clone(), a)]);
+        assert!(single.mod_equal(&multi));
+
+        let multi = multi_scalarmul_non_secret(&zero, &[(p.clone(), a)]);
+        assert!(p.point_scalar_mul(&a).mod_equal(&multi));
+
+        let multi =
+            multi_scalarmul_non_secret(&zero, &[(p.clone(), b), (p, scalar::sub(&zero, &b))]);
+        assert!(multi.is_identity());
+        assert!(multi_scalarmul_non_secret(&zero, &[]).is_identity());
+    }
+
+    #[test]
+    pub fn test_eddsa_like_decode_times_four() {
+        let scalar_four: Scalar = [4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut encoded: [u8; 57] = [0; 57];
+        encoded[..56].copy_from_slice(&[
+            0xa5, 0xd9, 0xce, 0xa4, 0x06, 0x89, 0xa4, 0x13, 0x94, 0xf0, 0x69, 0x32, 0xfe, 0xe0,
+            0xdb, 0x11, 0x7b, 0xe0, 0x75, 0x78, 0x68, 0x2c, 0x48, 0x44, 0x70, 0x3b, 0xe9, 0xc6,
+            0x64, 0xde, 0x6c, 0xe0, 0xd6, 0xa5, 0xa3, 0x4e, 0xe7, 0x38, 0xd9, 0xb3, 0x0c, 0x93,
+            0x75, 0x75, 0x8d, 0xe8, 0x50, 0xde, 0x06, 0x2c, 0xb9, 0x75, 0x50, 0x7d, 0x24, 0x85,
+        ]);
+        let p = eddsa_like_decode(&encoded).unwrap();
+        let q = eddsa_like_decode_times_four(&encoded).unwrap();
+        assert!(p.point_scalar_mul(&scalar_four).mod_equal(&q));
+        assert!(q.is_on_curve());
+
+        let mut identity: [u8; 57] = [0; 57];
+        identity[0] = 1;
+        assert!(eddsa_like_decode_times_four(&identity)
+            .unwrap()
+            .is_identity());
+    }
+
+    #[test]
+    pub fn test_eddsa_like_decompress_non_canonical() {
+        let mut identity: [u8; 57] = [0; 57];
+        identity[0] = 1;
+        let (x, y) = eddsa_like_decompress(&identity).unwrap();
+        assert_eq!(decaf_equal(&x, &BIG_ZERO), DECAF_TRUE);
+        assert_eq!(decaf_equal(&y, &BIG_ONE), DECAF_TRUE);
+
+        // x = 0 with the sign bit set.
+        let mut negative_zero = identity;
+        negative_zero[56] = 0x80;
+        // Reserved bits of the final byte.
+        let mut reserved = identity;
+        reserved[56] = 0x01;
+        // y = p, which would otherwise decode as y = 0.
+        let mut y_is_p: [u8; 57] = [0xff; 57];
+        y_is_p[28] = 0xfe;
+        y_is_p[56] = 0;
+        // y = p + 1, which would otherwise decode as the identity.
+        let mut large_y: [u8; 57] = [0; 57];
+        large_y[28..56].fill(0xff);
+
+        for encoded in [negative_zero, reserved, y_is_p, large_y] {
+            assert!(matches!(
+                eddsa_like_decompress(&encoded),
+                Err(LibgoldilockErrors::NonCanonicalEncodingError)
+            ));
+            assert!(matches!(
+                eddsa_like_decode(&encoded),
+                Err(LibgoldilockErrors::NonCanonicalEncodingError)
+            ));
+        }
+    }
+
     #[test]
     pub fn test_eddsa_like_decode() {
         let ser: [u8; 57] = [
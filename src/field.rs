@@ -0,0 +1,202 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::bignumber::{
+    self, decaf_cond_negate, decaf_equal, deserialize_return_mask, dsa_like_serialize, isr,
+    low_bit, square_n,
+};
+use crate::constants32::{BigNumber, BIG_ONE, BIG_ZERO, DECAF_TRUE, FIELD_BYTES};
+
+/// An element of GF(p) for p = 2^448 - 2^224 - 1, the field Ed448 and
+/// Decaf448 are defined over.
+///
+/// Equality is checked in constant time. Since p = 3 mod 4, -1 is not a
+/// square and stands in for the non-square of RFC 9496.
+#[derive(Clone, Copy)]
+pub struct FieldElement(pub(crate) BigNumber);
+
+impl FieldElement {
+    pub const ZERO: FieldElement = FieldElement(BIG_ZERO);
+    pub const ONE: FieldElement = FieldElement(BIG_ONE);
+
+    /// Decodes a 56-byte little-endian integer, returning `None` unless it
+    /// is below p.
+    pub fn from_bytes(bytes: &[u8; 56]) -> Option<FieldElement> {
+        let (n, mask) = deserialize_return_mask(*bytes);
+        if mask != DECAF_TRUE {
+            return None;
+        }
+        Some(FieldElement(n))
+    }
+
+    /// The canonical 56-byte little-endian encoding.
+    pub fn to_bytes(&self) -> [u8; FIELD_BYTES] {
+        dsa_like_serialize(&self.0)
+    }
+
+    /// Whether the canonical representative is odd, the sign convention of
+    /// RFC 8032 and RFC 9496.
+    pub fn is_negative(&self) -> bool {
+        low_bit(&self.0) == DECAF_TRUE
+    }
+
+    /// The element or its negation, whichever is not negative.
+    pub fn abs(&self) -> FieldElement {
+        FieldElement(decaf_cond_negate(&self.0, &low_bit(&self.0)))
+    }
+
+    pub fn square(&self) -> FieldElement {
+        FieldElement(bignumber::square(&self.0))
+    }
+
+    /// Squares `n` times, giving self^(2^n).
+    pub fn square_n(&self, n: usize) -> FieldElement {
+        if n == 0 {
+            return *self;
+        }
+        FieldElement(square_n(&self.0, n))
+    }
+
+    /// The multiplicative inverse. Zero maps to zero.
+    pub fn invert(&self) -> FieldElement {
+        FieldElement(bignumber::invert(&self.0))
+    }
+
+    /// For v != 0, returns `(true, sqrt(u / v))` when u / v is a square and
+    /// `(false, sqrt(-u / v))` otherwise. For v = 0, returns `(true, 0)` when
+    /// u = 0 and `(false, 0)` otherwise. The root is never negative.
+    pub fn sqrt_ratio(u: &FieldElement, v: &FieldElement) -> (bool, FieldElement) {
+        // r = u (u v)^((p - 3) / 4), so r^2 v = u (u v)^((p - 1) / 2) = +-u.
+        let r = bignumber::mul(&isr(&bignumber::mul(&u.0, &v.0)), &u.0);
+        let check = bignumber::mul(&bignumber::square(&r), &v.0);
+        let was_square = decaf_equal(&check, &u.0);
+
+        (was_square == DECAF_TRUE, FieldElement(r).abs())
+    }
+}
+
+impl Default for FieldElement {
+    fn default() -> Self {
+        FieldElement::ZERO
+    }
+}
+
+impl PartialEq for FieldElement {
+    fn eq(&self, other: &Self) -> bool {
+        decaf_equal(&self.0, &other.0) == DECAF_TRUE
+    }
+}
+
+impl Eq for FieldElement {}
+
+impl fmt::Debug for FieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FieldElement({})", hex::encode(self.to_bytes()))
+    }
+}
+
+impl Add for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: FieldElement) -> FieldElement {
+        FieldElement(bignumber::add(&self.0, &other.0))
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: FieldElement) -> FieldElement {
+        FieldElement(bignumber::sub(&self.0, &other.0))
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: FieldElement) -> FieldElement {
+        FieldElement(bignumber::mul(&self.0, &other.0))
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        FieldElement(bignumber::neg(&self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_u8(n: u8) -> FieldElement {
+        let mut bytes: [u8; 56] = [0; 56];
+        bytes[0] = n;
+        FieldElement::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    pub fn test_field_arithmetic() {
+        let two = from_u8(2);
+        let three = from_u8(3);
+
+        assert_eq!(two + three, from_u8(5));
+        assert_eq!(two - three, -FieldElement::ONE);
+        assert_eq!(two * three, from_u8(6));
+        assert_eq!(three.square(), from_u8(9));
+        assert_eq!(two.square_n(0), two);
+        assert_eq!(two.square_n(3), from_u8(16).square());
+        assert_eq!(three * three.invert(), FieldElement::ONE);
+        assert_eq!(FieldElement::ZERO.invert(), FieldElement::ZERO);
+
+        assert!(FieldElement::ONE.is_negative());
+        assert!(!(-FieldElement::ONE).is_negative());
+        assert!(!(-FieldElement::ONE).abs().is_negative());
+        assert_eq!(FieldElement::ONE.abs(), -FieldElement::ONE);
+    }
+
+    #[test]
+    pub fn test_field_encoding() {
+        let minus_one = (-FieldElement::ONE).to_bytes();
+        assert_eq!(
+            FieldElement::from_bytes(&minus_one),
+            Some(-FieldElement::ONE)
+        );
+
+        // p itself, and the largest 56-byte value.
+        let mut p = minus_one;
+        p[0] += 1;
+        assert!(FieldElement::from_bytes(&p).is_none());
+        assert!(FieldElement::from_bytes(&[0xff; 56]).is_none());
+
+        let x = from_u8(7).invert();
+        assert_eq!(FieldElement::from_bytes(&x.to_bytes()), Some(x));
+    }
+
+    #[test]
+    pub fn test_sqrt_ratio() {
+        let four = from_u8(4);
+        let nine = from_u8(9);
+
+        let (was_square, r) = FieldElement::sqrt_ratio(&four, &nine);
+        assert!(was_square);
+        assert!(!r.is_negative());
+        assert_eq!(r.square() * nine, four);
+
+        // -1 is not a square, so -4/9 is not either.
+        let (was_square, r) = FieldElement::sqrt_ratio(&-four, &nine);
+        assert!(!was_square);
+        assert_eq!(r.square() * nine, four);
+
+        assert_eq!(
+            FieldElement::sqrt_ratio(&FieldElement::ZERO, &FieldElement::ZERO),
+            (true, FieldElement::ZERO)
+        );
+        assert_eq!(
+            FieldElement::sqrt_ratio(&FieldElement::ONE, &FieldElement::ZERO),
+            (false, FieldElement::ZERO)
+        );
+    }
+}
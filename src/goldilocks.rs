@@ -1,31 +1,47 @@
-use rand::RngCore;
+use rand::{CryptoRng, RngCore};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::errors::LibgoldilockErrors;
 use crate::{
-    eddsa::{clamp, dsa_verify, hash_with_dom, sha3},
-    extended_point::{precomputed_scalar_mul, TwistedExtendedPoint},
-    scalar::{self, decode_long, encode, halve},
+    eddsa::{
+        check_context, check_prehash, clamp, dsa_verify, hash_with_dom, prehash, sha3,
+        validate_pubkey, PREHASH_LENGTH,
+    },
+    extended_point::{
+        checked_precomputed_scalar_mul, precomputed_scalar_mul, TwistedExtendedPoint,
+    },
+    scalar::{self, decode_long, encode, halve, Scalar},
 };
 
 pub type PrivateKey = [u8; 57];
 pub type PublicKey = [u8; 57];
 
-pub fn hex_to_message_hash(hexx: &str) -> [u8; 32] {
-    let mut p: [u8; 32] = [0; 32];
-    hex::decode_to_slice(hexx, &mut p).expect("Decoding failed");
-    p
+// Decodes exactly N bytes of hex; a string of the wrong length is reported
+// with the number of bytes it would have decoded to.
+fn hex_to_array<const N: usize>(hexx: &str) -> Result<[u8; N], LibgoldilockErrors> {
+    let mut out: [u8; N] = [0; N];
+    match hex::decode_to_slice(hexx, &mut out) {
+        Ok(()) => Ok(out),
+        Err(hex::FromHexError::InvalidStringLength) => {
+            Err(LibgoldilockErrors::InvalidLengthError {
+                expected: N,
+                actual: hexx.len() / 2,
+            })
+        }
+        Err(err) => Err(err.into()),
+    }
 }
 
-pub fn hex_to_private_key(hexx: &str) -> PrivateKey {
-    let mut p: PrivateKey = [0; 57];
-    hex::decode_to_slice(hexx, &mut p).expect("Decoding failed");
-    p
+pub fn hex_to_message_hash(hexx: &str) -> Result<[u8; 32], LibgoldilockErrors> {
+    hex_to_array(hexx)
 }
 
-pub fn hex_to_signature(hexx: &str) -> [u8; 114] {
-    let mut s: [u8; 114] = [0; 114];
-    hex::decode_to_slice(hexx, &mut s).expect("Decoding failed");
-    s
+pub fn hex_to_private_key(hexx: &str) -> Result<PrivateKey, LibgoldilockErrors> {
+    hex_to_array(hexx)
+}
+
+pub fn hex_to_signature(hexx: &str) -> Result<[u8; 114], LibgoldilockErrors> {
+    hex_to_array(hexx)
 }
 
 pub fn point_by_secret(p: &PrivateKey) -> TwistedExtendedPoint {
@@ -35,13 +51,16 @@ pub fn point_by_secret(p: &PrivateKey) -> TwistedExtendedPoint {
     let mut r = decode_long(&digest);
     r = halve(r);
     r = halve(r);
-    precomputed_scalar_mul(r)
+    let point = precomputed_scalar_mul(r);
+
+    digest.zeroize();
+    r.zeroize();
+    point
 }
 
 pub fn private_to_secret(pk: &PrivateKey) -> PrivateKey {
     let mut sk: PrivateKey = [0; 57];
     sha3(pk, &mut sk);
-    sk[56] |= 0x80;
     sk
 }
 
@@ -53,106 +72,368 @@ pub fn secret_to_public(sk: &PrivateKey) -> PublicKey {
 }
 
 pub fn private_to_public(pk: &PrivateKey) -> PublicKey {
-    secret_to_public(&private_to_secret(pk))
+    let mut sk = private_to_secret(pk);
+    let public = secret_to_public(&sk);
+
+    sk.zeroize();
+    public
 }
 
-pub fn sign_by_private(pk: &PrivateKey, message: &[u8]) -> [u8; 114] {
+pub fn expand_seed(pk: &PrivateKey) -> (PrivateKey, PrivateKey) {
     let mut secret: [u8; 114] = [0; 114];
     sha3(pk, &mut secret);
     clamp(&mut secret);
     let mut sk: [u8; 57] = [0; 57];
     sk.clone_from_slice(&secret[0..57]);
-    let sec = decode_long(&sk);
     let mut seed: [u8; 57] = [0; 57];
     seed.clone_from_slice(&secret[57..114]);
-    let point = point_by_secret(&sk);
 
-    let mut nonce: [u8; 114] = [0; 114];
-    let mut v1: Vec<u8> = seed.to_vec();
-    v1.append(&mut message.to_vec());
-    hash_with_dom(&mut v1, &mut nonce);
-    let nonce_scalar = decode_long(&nonce);
+    secret.zeroize();
+    (sk, seed)
+}
+
+pub fn nonce_from_hash(nonce: &[u8; 114]) -> (Scalar, [u8; 57]) {
+    let nonce_scalar = decode_long(nonce);
     let mut nonce_scalar2 = nonce_scalar;
     nonce_scalar2 = halve(nonce_scalar2);
     nonce_scalar2 = halve(nonce_scalar2);
     let nonce_point = precomputed_scalar_mul(nonce_scalar2).eddsa_like_encode();
 
-    let mut challenge: [u8; 114] = [0; 114];
-    let mut h = nonce_point.to_vec();
-    h.append(&mut point.eddsa_like_encode().to_vec());
-    h.append(&mut message.to_vec());
-    hash_with_dom(&mut h, &mut challenge);
+    nonce_scalar2.zeroize();
+    (nonce_scalar, nonce_point)
+}
 
-    let mut challenge_scalar = decode_long(&challenge);
-    challenge_scalar = scalar::mul(&challenge_scalar, &sec);
-    challenge_scalar = scalar::add(&challenge_scalar, &nonce_scalar);
+pub fn signature_from_challenge(
+    challenge: &[u8; 114],
+    sec: &Scalar,
+    nonce_scalar: &Scalar,
+    nonce_point: &[u8; 57],
+) -> [u8; 114] {
+    let mut challenge_scalar = decode_long(challenge);
+    challenge_scalar = scalar::mul(&challenge_scalar, sec);
+    challenge_scalar = scalar::add(&challenge_scalar, nonce_scalar);
 
     let mut result: [u8; 114] = [0; 114];
-    result[0..57].copy_from_slice(&nonce_point);
+    result[0..57].copy_from_slice(nonce_point);
     result[57..114].copy_from_slice(&encode(&challenge_scalar));
 
     result
 }
 
+pub fn sign_by_private(
+    pk: &PrivateKey,
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
+) -> [u8; 114] {
+    let (mut sk, mut seed) = expand_seed(pk);
+    let sig = sign_with_secret_and_nonce(&sk, &seed, message, prehashed, context);
+
+    sk.zeroize();
+    seed.zeroize();
+    sig
+}
+
 pub fn sign_with_secret_and_nonce(
     secret: &PrivateKey,
     n: &PrivateKey,
-    message: &[u8],
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
+) -> [u8; 114] {
+    sign_with_nonce_parts(secret, &[n], message, prehashed, context)
+}
+
+// Like `sign_with_secret_and_nonce`, with the nonce hashed from
+// dom4 || nonce_head || message.
+fn sign_with_nonce_parts(
+    secret: &PrivateKey,
+    nonce_head: &[&[u8]],
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
 ) -> [u8; 114] {
     let mut s1 = *secret;
     clamp(&mut s1);
-    let sec = decode_long(&s1);
+    let mut sec = decode_long(&s1);
 
     let pub_point = point_by_secret(secret);
 
     let mut nonce: [u8; 114] = [0; 114];
-    let mut v1: Vec<u8> = n.to_vec();
-    v1.append(&mut message.to_vec());
-    hash_with_dom(&mut v1, &mut nonce);
-    let nonce_scalar = decode_long(&nonce);
-    let mut nonce_scalar2 = nonce_scalar;
-    nonce_scalar2 = halve(nonce_scalar2);
-    nonce_scalar2 = halve(nonce_scalar2);
-    let nonce_point = precomputed_scalar_mul(nonce_scalar2).eddsa_like_encode();
+    hash_with_dom(nonce_head, message, &mut nonce, prehashed, context);
+    let (mut nonce_scalar, nonce_point) = nonce_from_hash(&nonce);
 
     let mut challenge: [u8; 114] = [0; 114];
-    let mut h = nonce_point.to_vec();
-    h.append(&mut pub_point.eddsa_like_encode().to_vec());
-    h.append(&mut message.to_vec());
-    hash_with_dom(&mut h, &mut challenge);
+    let public = pub_point.eddsa_like_encode();
+    hash_with_dom(
+        &[&nonce_point, &public],
+        message,
+        &mut challenge,
+        prehashed,
+        context,
+    );
+
+    let sig = signature_from_challenge(&challenge, &sec, &nonce_scalar, &nonce_point);
+
+    s1.zeroize();
+    sec.zeroize();
+    nonce.zeroize();
+    nonce_scalar.zeroize();
+    sig
+}
 
-    let mut challenge_scalar = decode_long(&challenge);
-    challenge_scalar = scalar::mul(&challenge_scalar, &sec);
-    challenge_scalar = scalar::add(&challenge_scalar, &nonce_scalar);
+// `pk` is always an RFC 8032 seed; keys that are already expanded go through
+// `ed448_derive_public_expanded`.
+pub fn ed448_derive_public(pk: &PrivateKey) -> PublicKey {
+    private_to_public(pk)
+}
 
-    let mut result: [u8; 114] = [0; 114];
-    result[0..57].copy_from_slice(&nonce_point);
-    result[57..114].copy_from_slice(&encode(&challenge_scalar));
+// Public key of an expanded secret, i.e. the first half of SHAKE256(seed, 114).
+pub fn ed448_derive_public_expanded(secret: &PrivateKey) -> PublicKey {
+    secret_to_public(secret)
+}
 
-    result
+pub fn ed448_sign(pk: &PrivateKey, message: &[u8]) -> [u8; 114] {
+    sign_by_private(pk, &[message], false, &[])
 }
 
-pub fn ed448_derive_public(pk: &PrivateKey) -> PublicKey {
-    if pk[57 - 1] & 0x80 == 0x00 {
-        private_to_public(pk)
-    } else {
-        secret_to_public(pk)
-    }
+pub fn ed448_sign_ctx(
+    pk: &PrivateKey,
+    message: &[u8],
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_context(context)?;
+    Ok(sign_by_private(pk, &[message], false, context))
 }
 
-pub fn ed448_sign(pk: &PrivateKey, message: &[u8]) -> [u8; 114] {
-    if pk[57 - 1] & 0x80 == 0x00 {
-        sign_by_private(pk, message)
-    } else {
-        let mut digest = *pk;
-        clamp(&mut digest);
+// Signs the concatenation of `message` without copying it into one buffer.
+pub fn ed448_sign_parts(
+    pk: &PrivateKey,
+    message: &[&[u8]],
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_context(context)?;
+    Ok(sign_by_private(pk, message, false, context))
+}
+
+pub fn ed448ph_prehash(message: &[u8]) -> [u8; PREHASH_LENGTH] {
+    prehash(message)
+}
+
+pub fn ed448ph_sign(
+    pk: &PrivateKey,
+    prehash: &[u8],
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    check_context(context)?;
+    Ok(sign_by_private(pk, &[prehash], true, context))
+}
+
+// Pure Ed448 with an expanded key: the secret scalar bytes and the nonce
+// prefix, the two halves of SHAKE256(seed, 114).
+pub fn ed448_sign_expanded(
+    secret: &PrivateKey,
+    prefix: &PrivateKey,
+    message: &[u8],
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_context(context)?;
+    Ok(sign_with_secret_and_nonce(
+        secret,
+        prefix,
+        &[message],
+        false,
+        context,
+    ))
+}
+
+pub fn ed448ph_sign_expanded(
+    secret: &PrivateKey,
+    prefix: &PrivateKey,
+    prehash: &[u8],
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    check_context(context)?;
+    Ok(sign_with_secret_and_nonce(
+        secret,
+        prefix,
+        &[prehash],
+        true,
+        context,
+    ))
+}
+
+pub const HEDGE_NOISE_LENGTH: usize = 64;
+
+// Hedged signing: fresh randomness Z from `rng` goes into the nonce next to
+// the secret prefix, r = SHAKE256(dom4 || prefix || Z || M, 114). The
+// signature verifies like any other Ed448 signature, but two signatures of
+// the same message differ.
+fn sign_hedged<R: RngCore + CryptoRng>(
+    pk: &PrivateKey,
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
+    rng: &mut R,
+) -> [u8; 114] {
+    let (mut secret, mut nonce) = expand_seed(pk);
+    let mut noise = hedge_noise(rng);
+    let sig = sign_with_nonce_parts(&secret, &[&nonce, &noise], message, prehashed, context);
+
+    secret.zeroize();
+    nonce.zeroize();
+    noise.zeroize();
+    sig
+}
+
+fn hedge_noise<R: RngCore + CryptoRng>(rng: &mut R) -> [u8; HEDGE_NOISE_LENGTH] {
+    let mut noise: [u8; HEDGE_NOISE_LENGTH] = [0; HEDGE_NOISE_LENGTH];
+    rng.fill_bytes(&mut noise);
+    noise
+}
+
+pub fn ed448_sign_hedged<R: RngCore + CryptoRng>(
+    pk: &PrivateKey,
+    message: &[u8],
+    context: &[u8],
+    rng: &mut R,
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_context(context)?;
+    Ok(sign_hedged(pk, &[message], false, context, rng))
+}
+
+pub fn ed448ph_sign_hedged<R: RngCore + CryptoRng>(
+    pk: &PrivateKey,
+    prehash: &[u8],
+    context: &[u8],
+    rng: &mut R,
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    check_context(context)?;
+    Ok(sign_hedged(pk, &[prehash], true, context, rng))
+}
 
-        sign_with_secret_and_nonce(&digest, &digest, message)
+// Fault-hardened signing, for `public` the trusted public key of `pk`.
+// Both fixed-base multiplications are redone with
+// `checked_precomputed_scalar_mul`, the recomputed public key and nonce point
+// must match the ones in use, and the signature must verify under `public`.
+// On any disagreement `FaultDetectedError` is returned and the signature is
+// dropped. An empty `noise` gives the deterministic RFC 8032 signature.
+fn sign_hardened(
+    pk: &PrivateKey,
+    public: &PublicKey,
+    noise: &[u8],
+    message: &[&[u8]],
+    prehashed: bool,
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    let (secret, nonce) = expand_seed(pk);
+    let (secret, nonce) = (Zeroizing::new(secret), Zeroizing::new(nonce));
+    let nonce_head: [&[u8]; 2] = [&nonce[..], noise];
+    let sig = sign_with_nonce_parts(&secret, &nonce_head, message, prehashed, context);
+
+    let sec = Zeroizing::new(halve(halve(decode_long(&secret[..]))));
+    let public_check = checked_precomputed_scalar_mul(*sec)?;
+    let mut nonce_hash = Zeroizing::new([0u8; 114]);
+    hash_with_dom(
+        &nonce_head,
+        message,
+        &mut nonce_hash[..],
+        prehashed,
+        context,
+    );
+    let nonce_scalar = Zeroizing::new(halve(halve(decode_long(&nonce_hash[..]))));
+    let nonce_check = checked_precomputed_scalar_mul(*nonce_scalar)?;
+    if public_check.eddsa_like_encode() != *public
+        || nonce_check.eddsa_like_encode()[..] != sig[..57]
+    {
+        return Err(LibgoldilockErrors::FaultDetectedError);
     }
+
+    match dsa_verify(public, &sig, message, prehashed, context) {
+        Ok(true) => Ok(sig),
+        _ => Err(LibgoldilockErrors::FaultDetectedError),
+    }
+}
+
+pub fn ed448_sign_hardened(
+    pk: &PrivateKey,
+    public: &PublicKey,
+    message: &[u8],
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_context(context)?;
+    sign_hardened(pk, public, &[], &[message], false, context)
+}
+
+pub fn ed448ph_sign_hardened(
+    pk: &PrivateKey,
+    public: &PublicKey,
+    prehash: &[u8],
+    context: &[u8],
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    check_context(context)?;
+    sign_hardened(pk, public, &[], &[prehash], true, context)
+}
+
+pub fn ed448_sign_hedged_hardened<R: RngCore + CryptoRng>(
+    pk: &PrivateKey,
+    public: &PublicKey,
+    message: &[u8],
+    context: &[u8],
+    rng: &mut R,
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_context(context)?;
+    let noise = Zeroizing::new(hedge_noise(rng));
+    sign_hardened(pk, public, &noise[..], &[message], false, context)
+}
+
+pub fn ed448ph_sign_hedged_hardened<R: RngCore + CryptoRng>(
+    pk: &PrivateKey,
+    public: &PublicKey,
+    prehash: &[u8],
+    context: &[u8],
+    rng: &mut R,
+) -> Result<[u8; 114], LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    check_context(context)?;
+    let noise = Zeroizing::new(hedge_noise(rng));
+    sign_hardened(pk, public, &noise[..], &[prehash], true, context)
 }
 
 pub fn ed448_verify(pubkey: &[u8], sig: &[u8], message: &[u8]) -> Result<bool, LibgoldilockErrors> {
-    dsa_verify(pubkey, sig, message)
+    dsa_verify(pubkey, sig, &[message], false, &[])
+}
+
+pub fn ed448_verify_ctx(
+    pubkey: &[u8],
+    sig: &[u8],
+    message: &[u8],
+    context: &[u8],
+) -> Result<bool, LibgoldilockErrors> {
+    dsa_verify(pubkey, sig, &[message], false, context)
+}
+
+pub fn ed448_verify_parts(
+    pubkey: &[u8],
+    sig: &[u8],
+    message: &[&[u8]],
+    context: &[u8],
+) -> Result<bool, LibgoldilockErrors> {
+    dsa_verify(pubkey, sig, message, false, context)
+}
+
+pub fn ed448ph_verify(
+    pubkey: &[u8],
+    sig: &[u8],
+    prehash: &[u8],
+    context: &[u8],
+) -> Result<bool, LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    dsa_verify(pubkey, sig, &[prehash], true, context)
 }
 
 pub fn ed448_verify_with_error(
@@ -160,13 +441,17 @@ pub fn ed448_verify_with_error(
     sig: &[u8],
     message: &[u8],
 ) -> Result<(), LibgoldilockErrors> {
-    let is_ok = dsa_verify(pubkey, sig, message)?;
+    let is_ok = dsa_verify(pubkey, sig, &[message], false, &[])?;
     if !is_ok {
         return Err(LibgoldilockErrors::InvalidSignatureError);
     }
     Ok(())
 }
 
+pub fn ed448_validate_public(pubkey: &[u8]) -> Result<(), LibgoldilockErrors> {
+    validate_pubkey(pubkey)
+}
+
 pub fn ed448_generate_key() -> PrivateKey {
     let mut random_key: PrivateKey = [0; 57];
     rand::thread_rng().fill_bytes(&mut random_key);
@@ -176,10 +461,11 @@ pub fn ed448_generate_key() -> PrivateKey {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::eddsa::add_group_order_to_scalar;
 
     #[test]
     pub fn test_decode_hex() {
-        let pk = hex_to_private_key("a8ea212cc24ae0fd029a97b64be540885af0e1b7dc9faf4a591742850c4377f857ae9a8f87df1de98e397a5867dd6f20211ef3f234ae71bc56");
+        let pk = hex_to_private_key("a8ea212cc24ae0fd029a97b64be540885af0e1b7dc9faf4a591742850c4377f857ae9a8f87df1de98e397a5867dd6f20211ef3f234ae71bc56").unwrap();
         let exp: PrivateKey = [
             168, 234, 33, 44, 194, 74, 224, 253, 2, 154, 151, 182, 75, 229, 64, 136, 90, 240, 225,
             183, 220, 159, 175, 74, 89, 23, 66, 133, 12, 67, 119, 248, 87, 174, 154, 143, 135, 223,
@@ -190,50 +476,55 @@ mod tests {
 
     #[test]
     pub fn test_private_to_secret() {
-        let pk = hex_to_private_key("a8ea212cc24ae0fd029a97b64be540885af0e1b7dc9faf4a591742850c4377f857ae9a8f87df1de98e397a5867dd6f20211ef3f234ae71bc56");
-        let exp = hex_to_private_key("1413821ed67083c855c6db4405dd4fa5fdec39e1c761be1415623c1c202c5cb5176e578830372b7e07eb1ef9cf71b19518815c4da0fd2d3594");
+        let pk = hex_to_private_key("a8ea212cc24ae0fd029a97b64be540885af0e1b7dc9faf4a591742850c4377f857ae9a8f87df1de98e397a5867dd6f20211ef3f234ae71bc56").unwrap();
+        let exp = hex_to_private_key("1413821ed67083c855c6db4405dd4fa5fdec39e1c761be1415623c1c202c5cb5176e578830372b7e07eb1ef9cf71b19518815c4da0fd2d3594").unwrap();
         let sk = private_to_secret(&pk);
         assert_eq!(sk, exp);
+
+        // The hash is returned as is, with no marker in the top byte.
+        let mut digest: PrivateKey = [0; 57];
+        sha3(&[0; 57], &mut digest);
+        assert_eq!(private_to_secret(&[0; 57]), digest);
     }
 
     #[test]
     pub fn test_secret_to_public() {
-        let pk = hex_to_private_key("1413821ed67083c855c6db4405dd4fa5fdec39e1c761be1415623c1c202c5cb5176e578830372b7e07eb1ef9cf71b19518815c4da0fd2d3594");
+        let pk = hex_to_private_key("1413821ed67083c855c6db4405dd4fa5fdec39e1c761be1415623c1c202c5cb5176e578830372b7e07eb1ef9cf71b19518815c4da0fd2d3594").unwrap();
         let pubk = secret_to_public(&pk);
-        let exp: PublicKey = hex_to_private_key("b615e57dd4d15c3ed1323725c0ba8b1d7f6e740d08e0e29c6d3ff564c896c0c3dd28a9bb5065e06725c8f9e3f7c2c6bbad4900b7447ecf9880");
+        let exp: PublicKey = hex_to_private_key("b615e57dd4d15c3ed1323725c0ba8b1d7f6e740d08e0e29c6d3ff564c896c0c3dd28a9bb5065e06725c8f9e3f7c2c6bbad4900b7447ecf9880").unwrap();
         assert_eq!(pubk, exp);
     }
 
     #[test]
     pub fn test_private_to_public() {
-        let pk = hex_to_private_key("a8ea212cc24ae0fd029a97b64be540885af0e1b7dc9faf4a591742850c4377f857ae9a8f87df1de98e397a5867dd6f20211ef3f234ae71bc56");
+        let pk = hex_to_private_key("a8ea212cc24ae0fd029a97b64be540885af0e1b7dc9faf4a591742850c4377f857ae9a8f87df1de98e397a5867dd6f20211ef3f234ae71bc56").unwrap();
         let pubk = private_to_public(&pk);
-        let exp: PublicKey = hex_to_private_key("b615e57dd4d15c3ed1323725c0ba8b1d7f6e740d08e0e29c6d3ff564c896c0c3dd28a9bb5065e06725c8f9e3f7c2c6bbad4900b7447ecf9880");
+        let exp: PublicKey = hex_to_private_key("b615e57dd4d15c3ed1323725c0ba8b1d7f6e740d08e0e29c6d3ff564c896c0c3dd28a9bb5065e06725c8f9e3f7c2c6bbad4900b7447ecf9880").unwrap();
         assert_eq!(pubk, exp);
     }
 
     #[test]
     pub fn test_sign_with_private() {
-        let pk = hex_to_private_key("64c2754ee8f55f285d1c6efac34345c78da28df5c31d9ae3748417e0754903004eca31389e978df148e3941de8d4c3585b6dd3669903f00bb5");
+        let pk = hex_to_private_key("64c2754ee8f55f285d1c6efac34345c78da28df5c31d9ae3748417e0754903004eca31389e978df148e3941de8d4c3585b6dd3669903f00bb5").unwrap();
         let fox = b"The quick brown fox jumps over the lazy dog";
-        let sig = sign_by_private(&pk, fox);
-        let sig2 = hex_to_signature("d3ffe2cffeba84f631c9e4f452c7f27023b48e679f30ad9f43b4ef0483670e25842efdd6a20ad74f2c08351e37857763c0e1b787a7a02c5c00708263b206ab852e865676b3b8ad2c86794cd2831b54064cda39e2703a4c172a1debf051e01ae981c58a577731127f2bfb7aaa3f9242572400");
+        let sig = sign_by_private(&pk, &[fox], false, &[]);
+        let sig2 = hex_to_signature("d3ffe2cffeba84f631c9e4f452c7f27023b48e679f30ad9f43b4ef0483670e25842efdd6a20ad74f2c08351e37857763c0e1b787a7a02c5c00708263b206ab852e865676b3b8ad2c86794cd2831b54064cda39e2703a4c172a1debf051e01ae981c58a577731127f2bfb7aaa3f9242572400").unwrap();
         assert_eq!(sig, sig2);
     }
 
     #[test]
     pub fn test_sign_with_secret_and_nonce() {
-        let pk = hex_to_private_key("26ad14d91ef8f1e5bbf5a1a7e44a9532e4854f1e1346761ee9b4ed1ed103e5e05c87fd9ecd788bc879a7433a7115255b7aad667fe84ee35c28");
-        let n = hex_to_private_key("66dd9754284a1b7d77c1c43bfdfe38a116bd143e7c901b8e8e4561a7ee0a401dd5120fa2b77e2a6bda3a68d5a47e34fd29cf14ce3489067602");
+        let pk = hex_to_private_key("26ad14d91ef8f1e5bbf5a1a7e44a9532e4854f1e1346761ee9b4ed1ed103e5e05c87fd9ecd788bc879a7433a7115255b7aad667fe84ee35c28").unwrap();
+        let n = hex_to_private_key("66dd9754284a1b7d77c1c43bfdfe38a116bd143e7c901b8e8e4561a7ee0a401dd5120fa2b77e2a6bda3a68d5a47e34fd29cf14ce3489067602").unwrap();
         let fox = b"The quick brown fox jumps over the lazy dog";
-        let sig = sign_with_secret_and_nonce(&pk, &n, fox);
-        let sig2 = hex_to_signature("71e4ae51aa4d1f59f10efaaca743ca557079c2de1d298375d80eac8c53d29567add49f6296206f6c0d56ad3cd3f34b3644b1b01361900bea803aae2018aea2db72a2c5557a207ba17b8316335817b4a9474def73b3ea0ddaaae593e76596fbeac45c8ef04df3bb23dc809d2b7db49dbf0a00");
+        let sig = sign_with_secret_and_nonce(&pk, &n, &[fox], false, &[]);
+        let sig2 = hex_to_signature("71e4ae51aa4d1f59f10efaaca743ca557079c2de1d298375d80eac8c53d29567add49f6296206f6c0d56ad3cd3f34b3644b1b01361900bea803aae2018aea2db72a2c5557a207ba17b8316335817b4a9474def73b3ea0ddaaae593e76596fbeac45c8ef04df3bb23dc809d2b7db49dbf0a00").unwrap();
         assert_eq!(sig, sig2);
     }
 
     // #[test]
     // pub fn test_secret_to_public_benchmarks() {
-    //     let pk = hex_to_private_key("1413821ed67083c855c6db4405dd4fa5fdec39e1c761be1415623c1c202c5cb5176e578830372b7e07eb1ef9cf71b19518815c4da0fd2d3594");
+    //     let pk = hex_to_private_key("1413821ed67083c855c6db4405dd4fa5fdec39e1c761be1415623c1c202c5cb5176e578830372b7e07eb1ef9cf71b19518815c4da0fd2d3594").unwrap();
     //     let before = SystemTime::now();
     //     for i in 0..10000 {
     //         let pubk = secret_to_public(&pk);
@@ -244,14 +535,14 @@ mod tests {
 
     // #[test]
     // pub fn test_overall() {
-    //     let pk = hex_to_private_key("64c2754ee8f55f285d1c6efac34345c78da28df5c31d9ae3748417e0754903004eca31389e978df148e3941de8d4c3585b6dd3669903f00bb5");
+    //     let pk = hex_to_private_key("64c2754ee8f55f285d1c6efac34345c78da28df5c31d9ae3748417e0754903004eca31389e978df148e3941de8d4c3585b6dd3669903f00bb5").unwrap();
     //     let fox = b"The quick brown fox jumps over the lazy dog";
     //     let sig = ed448_sign(&pk, fox);
     //     let public = private_to_public(&pk);
     //     let mut v = ed448_verify(&public, &sig, fox);
     //     assert_eq!(v, true);
 
-    //     let pk1 = hex_to_private_key("64c2754ee8f55f285d1c6efac34345c78da28df5c31d9ae3748417e0754903004eca31389e978df148e3941de8d4c3585b6dd3669903f00bb6");
+    //     let pk1 = hex_to_private_key("64c2754ee8f55f285d1c6efac34345c78da28df5c31d9ae3748417e0754903004eca31389e978df148e3941de8d4c3585b6dd3669903f00bb6").unwrap();
     //     let public1 = ed448_derive_public(&pk1);
     //     v = ed448_verify(&public1, &sig, fox);
     //     assert_eq!(v, false);
@@ -260,31 +551,161 @@ mod tests {
 
     #[test]
     pub fn test_ed448_derive_public() {
-        let mut pk = hex_to_private_key("582f73eb3d951ef93a8c392c7b113ad85c0f60a744c95c47370d4d593593edc0d745eb24fa2130f51fd5b1e6b2363a5405bf1e074ecbf4382d");
+        let mut pk = hex_to_private_key("582f73eb3d951ef93a8c392c7b113ad85c0f60a744c95c47370d4d593593edc0d745eb24fa2130f51fd5b1e6b2363a5405bf1e074ecbf4382d").unwrap();
         let mut pubk = ed448_derive_public(&pk);
-        let mut exp: PublicKey = hex_to_private_key("4e6ef3aa2a74ce85c9c75de379c72abbce30601db4f66af1535d00190fa5de83af3831fa32e37c59e14a25788e56140896fb59b494e4fdca80");
+        let mut exp: PublicKey = hex_to_private_key("4e6ef3aa2a74ce85c9c75de379c72abbce30601db4f66af1535d00190fa5de83af3831fa32e37c59e14a25788e56140896fb59b494e4fdca80").unwrap();
         assert_eq!(pubk, exp);
 
-        pk = hex_to_private_key("59fc82f514f3fc8d02d987e52a03cdcae81a257bed6ec9b668bf6acd8fe9e7d27cbcc4d8f463d917642d30e7ca44c3521370f78790b3b561dd");
-        pubk = ed448_derive_public(&pk);
-        exp = hex_to_private_key("3cba3b2560c2779170ce5947f55bf73b93a1dd51d99b0b483ed0cfb5a9bb8409830c0f96068c799dbc6a28ca6bc1aad95d0387c36a731d7800");
+        // An expanded secret rather than a seed.
+        pk = hex_to_private_key("59fc82f514f3fc8d02d987e52a03cdcae81a257bed6ec9b668bf6acd8fe9e7d27cbcc4d8f463d917642d30e7ca44c3521370f78790b3b561dd").unwrap();
+        pubk = ed448_derive_public_expanded(&pk);
+        exp = hex_to_private_key("3cba3b2560c2779170ce5947f55bf73b93a1dd51d99b0b483ed0cfb5a9bb8409830c0f96068c799dbc6a28ca6bc1aad95d0387c36a731d7800").unwrap();
         assert_eq!(pubk, exp);
     }
 
     #[test]
     pub fn test_ed448_sign() {
-        let mut pk = hex_to_private_key("e959068474bc720bf3a94c7a524750f0d4fe68a4828137e58d48303af1fa929a6c50f87d0cab27fc557aa1a3190cfad0abbca2a2e5d7da272d");
+        let mut pk = hex_to_private_key("e959068474bc720bf3a94c7a524750f0d4fe68a4828137e58d48303af1fa929a6c50f87d0cab27fc557aa1a3190cfad0abbca2a2e5d7da272d").unwrap();
         let fox = b"The quick brown fox jumps over the lazy dog";
         let mut sig = ed448_sign(&pk, fox);
-        let mut sig2 = hex_to_signature("92a7e08f86b25f288eb0308f3fb780950ab77c333d5d1b91b6de40a199fc028fe66a001dc09341905a58f8c3d4a959ee5d416735f59d91640095dd83e70b6bc05fa6a26b32c00be454bfb87285417554183c2da64bbbad77b746bd86299fd4188578bc9aa321a8291c5d2281029ca24e2d00");
+        let mut sig2 = hex_to_signature("92a7e08f86b25f288eb0308f3fb780950ab77c333d5d1b91b6de40a199fc028fe66a001dc09341905a58f8c3d4a959ee5d416735f59d91640095dd83e70b6bc05fa6a26b32c00be454bfb87285417554183c2da64bbbad77b746bd86299fd4188578bc9aa321a8291c5d2281029ca24e2d00").unwrap();
         assert_eq!(sig, sig2);
 
-        pk = hex_to_private_key("1edc2069350104b5594c602f7967c4b1580f2a757fc9a2745f621868cd333c245ec3c775d730d3c01a2e18f3e5d0b5e767ed3ec77e69732781");
-        sig = ed448_sign(&pk, fox);
-        sig2 = hex_to_signature("789dd9e1a4471c30cfef1da68076542e6918676424593936dbeb282f5929dcfa3437aef85fd890999ea7a1b16a2c8c3a8cf330c58768789b006b183034ec43acab783039d53fe46f6c39ab29f988a43371d07fe7746a2fd45c660f2a8c441446b8f1cdbfc0787e4cfe69280e5cd7b92d0400");
+        // An expanded secret, with its clamped bytes reused as the nonce prefix.
+        pk = hex_to_private_key("1edc2069350104b5594c602f7967c4b1580f2a757fc9a2745f621868cd333c245ec3c775d730d3c01a2e18f3e5d0b5e767ed3ec77e69732781").unwrap();
+        let mut prefix = pk;
+        clamp(&mut prefix);
+        sig = ed448_sign_expanded(&pk, &prefix, fox, &[]).unwrap();
+        sig2 = hex_to_signature("789dd9e1a4471c30cfef1da68076542e6918676424593936dbeb282f5929dcfa3437aef85fd890999ea7a1b16a2c8c3a8cf330c58768789b006b183034ec43acab783039d53fe46f6c39ab29f988a43371d07fe7746a2fd45c660f2a8c441446b8f1cdbfc0787e4cfe69280e5cd7b92d0400").unwrap();
         assert_eq!(sig, sig2);
     }
 
+    #[test]
+    pub fn test_ed448_sign_ctx_rfc8032() {
+        // RFC 8032, section 7.4: "1 octet" and "1 octet (with context)".
+        let pk = hex_to_private_key("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let public = hex_to_private_key("43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480").unwrap();
+        let message = [0x03];
+        assert_eq!(ed448_derive_public(&pk), public);
+
+        let mut sig = ed448_sign_ctx(&pk, &message, &[]).unwrap();
+        let mut exp = hex_to_signature("26b8f91727bd62897af15e41eb43c377efb9c610d48f2335cb0bd0087810f4352541b143c4b981b7e18f62de8ccdf633fc1bf037ab7cd779805e0dbcc0aae1cbcee1afb2e027df36bc04dcecbf154336c19f0af7e0a6472905e799f1953d2a0ff3348ab21aa4adafd1d234441cf807c03a00").unwrap();
+        assert_eq!(sig, exp);
+        assert_eq!(sig, ed448_sign(&pk, &message));
+
+        let context = b"foo";
+        sig = ed448_sign_ctx(&pk, &message, context).unwrap();
+        exp = hex_to_signature("d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d5428407e85dcbc98a49155c13764e66c3c00").unwrap();
+        assert_eq!(sig, exp);
+
+        assert!(ed448_verify_ctx(&public, &sig, &message, context).unwrap());
+        assert!(!ed448_verify_ctx(&public, &sig, &message, b"bar").unwrap());
+        assert!(!ed448_verify(&public, &sig, &message).unwrap());
+    }
+
+    #[test]
+    pub fn test_ed448_verify_rejects_malleated_s() {
+        let pk = ed448_generate_key();
+        let public = ed448_derive_public(&pk);
+        let fox = b"The quick brown fox jumps over the lazy dog";
+        let sig = ed448_sign(&pk, fox);
+        assert!(ed448_verify(&public, &sig, fox).unwrap());
+
+        let malleated = add_group_order_to_scalar(&sig);
+        assert!(matches!(
+            ed448_verify(&public, &malleated, fox),
+            Err(LibgoldilockErrors::NonCanonicalScalarError)
+        ));
+
+        let mut high_byte = sig;
+        high_byte[113] = 0x80;
+        assert!(matches!(
+            ed448_verify(&public, &high_byte, fox),
+            Err(LibgoldilockErrors::NonCanonicalScalarError)
+        ));
+    }
+
+    #[test]
+    pub fn test_ed448_sign_ctx_too_long() {
+        let pk = ed448_generate_key();
+        let fox = b"The quick brown fox jumps over the lazy dog";
+        let context = [0x42; 256];
+        assert!(matches!(
+            ed448_sign_ctx(&pk, fox, &context),
+            Err(LibgoldilockErrors::ContextTooLongError { .. })
+        ));
+
+        let sig = ed448_sign_ctx(&pk, fox, &context[..255]).unwrap();
+        let public = ed448_derive_public(&pk);
+        assert!(ed448_verify_ctx(&public, &sig, fox, &context[..255]).unwrap());
+    }
+
+    #[test]
+    pub fn test_ed448ph_sign_rfc8032() {
+        // RFC 8032, section 7.5: "TEST abc" and "TEST abc (with context)".
+        let pk = hex_to_private_key("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49").unwrap();
+        let public = hex_to_private_key("259b71c19f83ef77a7abd26524cbdb3161b590a48f7d17de3ee0ba9c52beb743c09428a131d6b1b57303d90d8132c276d5ed3d5d01c0f53880").unwrap();
+        let prehash = ed448ph_prehash(b"abc");
+        assert_eq!(ed448_derive_public(&pk), public);
+
+        let mut sig = ed448ph_sign(&pk, &prehash, &[]).unwrap();
+        let mut exp = hex_to_signature("822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae381f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b801a0d9b3f4030cd433964b6457ea39476511214f97469b57dd32dbc560a9a94d00bff07620464a3ad203df7dc7ce360c3cd3696d9d9fab90f00").unwrap();
+        assert_eq!(sig, exp);
+        assert!(ed448ph_verify(&public, &sig, &prehash, &[]).unwrap());
+        assert!(!ed448_verify(&public, &sig, &prehash).unwrap());
+
+        let context = b"foo";
+        sig = ed448ph_sign(&pk, &prehash, context).unwrap();
+        exp = hex_to_signature("c32299d46ec8ff02b54540982814dce9a05812f81962b649d528095916a2aa481065b1580423ef927ecf0af5888f90da0f6a9a85ad5dc3f280d91224ba9911a3653d00e484e2ce232521481c8658df304bb7745a73514cdb9bf3e15784ab71284f8d0704a608c54a6b62d97beb511d132100").unwrap();
+        assert_eq!(sig, exp);
+        assert!(ed448ph_verify(&public, &sig, &prehash, context).unwrap());
+        assert!(!ed448ph_verify(&public, &sig, &prehash, &[]).unwrap());
+    }
+
+    #[test]
+    pub fn test_ed448ph_invalid_prehash_length() {
+        let pk = ed448_generate_key();
+        let public = ed448_derive_public(&pk);
+        assert!(matches!(
+            ed448ph_sign(&pk, b"abc", &[]),
+            Err(LibgoldilockErrors::InvalidPrehashLengthError {
+                expected: 64,
+                actual: 3
+            })
+        ));
+        assert!(matches!(
+            ed448ph_verify(&public, &[0; 114], &[0; 63], &[]),
+            Err(LibgoldilockErrors::InvalidPrehashLengthError {
+                expected: 64,
+                actual: 63
+            })
+        ));
+    }
+
+    #[test]
+    pub fn test_ed448_sign_parts() {
+        let pk = ed448_generate_key();
+        let public = ed448_derive_public(&pk);
+        let fox = b"The quick brown fox jumps over the lazy dog";
+        let parts: [&[u8]; 4] = [
+            b"The quick",
+            b" brown fox ",
+            b"",
+            b"jumps over the lazy dog",
+        ];
+
+        let sig = ed448_sign_parts(&pk, &parts, &[]).unwrap();
+        assert_eq!(sig, ed448_sign(&pk, fox));
+        assert!(ed448_verify_parts(&public, &sig, &parts, &[]).unwrap());
+        assert!(ed448_verify_parts(&public, &sig, &[fox], &[]).unwrap());
+        assert!(!ed448_verify_parts(&public, &sig, &parts[1..], &[]).unwrap());
+
+        let sig = ed448_sign_parts(&pk, &parts, b"frame").unwrap();
+        assert_eq!(sig, ed448_sign_ctx(&pk, fox, b"frame").unwrap());
+        assert!(ed448_verify_parts(&public, &sig, &parts, b"frame").unwrap());
+        assert!(ed448_sign_parts(&pk, &parts, &[0; 256]).is_err());
+    }
+
     #[test]
     pub fn batch_test_sign_verify() {
         let n: usize = 100;
@@ -294,19 +715,97 @@ mod tests {
             let sig = ed448_sign(&pk, fox);
             let true_pub = ed448_derive_public(&pk);
             let mut result = ed448_verify(&true_pub, &sig, fox);
-            assert_eq!(result.unwrap(), true);
+            assert!(result.unwrap());
             let false_pub = ed448_derive_public(&ed448_generate_key());
             result = ed448_verify(&false_pub, &sig, fox);
-            assert_eq!(result.unwrap(), false);
+            assert!(!result.unwrap());
         }
     }
 
     // #[test]
     // pub fn test_ed448_verify() {
-    //     let mut sig = hex_to_signature("fe25200421dd73065668979b4cedc19ddd8536db632d4bc61a569cc07906cc9485c2b1999dcd2234d18e7393b5ec8f21802bd76b6fddb08b808be5264c2a7992474e7efa947019dedb0a0ab5405313837c2270f7b56dfe57b5ccbe6df20f5866231b1ce0df77aeb603944500d0c5e22b3000");
-    //     let mut pk = hex_to_private_key("bddc6f8ef904cc39eff871720fcf794575aa285809c09602ceff06fd741e8a39b2304779778e20c9ac76bc4c5628b6f1ad03d49d3dd09b4380");
-    //     let message = hex_to_message_hash("1f7d6d8c8133fb9807148f7188b797ac3d6308df12fc03d37de6ec5088c2f547");
+    //     let mut sig = hex_to_signature("fe25200421dd73065668979b4cedc19ddd8536db632d4bc61a569cc07906cc9485c2b1999dcd2234d18e7393b5ec8f21802bd76b6fddb08b808be5264c2a7992474e7efa947019dedb0a0ab5405313837c2270f7b56dfe57b5ccbe6df20f5866231b1ce0df77aeb603944500d0c5e22b3000").unwrap();
+    //     let mut pk = hex_to_private_key("bddc6f8ef904cc39eff871720fcf794575aa285809c09602ceff06fd741e8a39b2304779778e20c9ac76bc4c5628b6f1ad03d49d3dd09b4380").unwrap();
+    //     let message = hex_to_message_hash("1f7d6d8c8133fb9807148f7188b797ac3d6308df12fc03d37de6ec5088c2f547").unwrap();
     //     let result = ed448_verify(&pk, &sig, &message).unwrap();
     //     println!("{:?}", result);
     // }
+
+    #[test]
+    pub fn test_ed448_sign_hedged() {
+        let mut rng = rand::thread_rng();
+        let pk = hex_to_private_key("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let public = ed448_derive_public(&pk);
+        let message = [0x03];
+
+        let first = ed448_sign_hedged(&pk, &message, b"foo", &mut rng).unwrap();
+        let second = ed448_sign_hedged(&pk, &message, b"foo", &mut rng).unwrap();
+        assert_ne!(first, second);
+        assert_ne!(first, ed448_sign_ctx(&pk, &message, b"foo").unwrap());
+        assert!(ed448_verify_ctx(&public, &first, &message, b"foo").unwrap());
+        assert!(ed448_verify_ctx(&public, &second, &message, b"foo").unwrap());
+
+        let prehash = ed448ph_prehash(b"abc");
+        let sig = ed448ph_sign_hedged(&pk, &prehash, &[], &mut rng).unwrap();
+        assert!(ed448ph_verify(&public, &sig, &prehash, &[]).unwrap());
+
+        assert!(matches!(
+            ed448_sign_hedged(&pk, &message, &[0; 256], &mut rng),
+            Err(LibgoldilockErrors::ContextTooLongError { .. })
+        ));
+    }
+
+    #[test]
+    pub fn test_ed448_sign_hardened() {
+        let mut rng = rand::thread_rng();
+        let pk = hex_to_private_key("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let public = ed448_derive_public(&pk);
+        let message = [0x03];
+
+        assert_eq!(
+            ed448_sign_hardened(&pk, &public, &message, b"foo").unwrap(),
+            ed448_sign_ctx(&pk, &message, b"foo").unwrap()
+        );
+        let prehash = ed448ph_prehash(b"abc");
+        assert_eq!(
+            ed448ph_sign_hardened(&pk, &public, &prehash, &[]).unwrap(),
+            ed448ph_sign(&pk, &prehash, &[]).unwrap()
+        );
+
+        let sig = ed448_sign_hedged_hardened(&pk, &public, &message, b"foo", &mut rng).unwrap();
+        assert!(ed448_verify_ctx(&public, &sig, &message, b"foo").unwrap());
+        let sig = ed448ph_sign_hedged_hardened(&pk, &public, &prehash, &[], &mut rng).unwrap();
+        assert!(ed448ph_verify(&public, &sig, &prehash, &[]).unwrap());
+
+        // A public key that does not belong to the secret is treated as a fault.
+        let other = ed448_derive_public(&ed448_generate_key());
+        assert!(matches!(
+            ed448_sign_hardened(&pk, &other, &message, &[]),
+            Err(LibgoldilockErrors::FaultDetectedError)
+        ));
+    }
+
+    #[test]
+    pub fn test_seed_with_high_bit() {
+        // The top bit of the last byte carries no meaning for a seed.
+        let mut pk = hex_to_private_key("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        pk[56] |= 0x80;
+        let (secret, prefix) = expand_seed(&pk);
+        let public = ed448_derive_public(&pk);
+        assert_eq!(public, ed448_derive_public_expanded(&secret));
+        assert_ne!(public, ed448_derive_public_expanded(&pk));
+
+        let sig = ed448_sign_ctx(&pk, b"message", b"ctx").unwrap();
+        assert_eq!(
+            sig,
+            ed448_sign_expanded(&secret, &prefix, b"message", b"ctx").unwrap()
+        );
+        assert!(ed448_verify_ctx(&public, &sig, b"message", b"ctx").unwrap());
+
+        let prehash = ed448ph_prehash(b"abc");
+        assert_eq!(
+            ed448ph_sign(&pk, &prehash, &[]).unwrap(),
+            ed448ph_sign_expanded(&secret, &prefix, &prehash, &[]).unwrap()
+        );
+    }
 }
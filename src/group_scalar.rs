@@ -0,0 +1,195 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use rand::{CryptoRng, Rng};
+use zeroize::Zeroize;
+
+use crate::bignumber::is_zero_mask;
+use crate::constants32::DECAF_TRUE;
+use crate::scalar::{self, decode_canonical, decode_long, encode};
+
+/// An integer modulo the order L of the Ed448 prime-order subgroup.
+///
+/// Equality is checked in constant time, and `Debug` leaves the value out
+/// since scalars are often secret.
+#[derive(Clone, Copy, Zeroize)]
+pub struct Scalar(pub(crate) scalar::Scalar);
+
+impl Scalar {
+    pub const ZERO: Scalar = Scalar([0; 14]);
+    pub const ONE: Scalar = Scalar([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Reduces a 57-byte little-endian integer modulo L.
+    pub fn from_bytes_mod_order(bytes: &[u8; 57]) -> Scalar {
+        Scalar(decode_long(bytes))
+    }
+
+    /// Reduces a 114-byte little-endian integer modulo L, such as a
+    /// SHAKE256 output. The result is close enough to uniform for hashing
+    /// to a scalar.
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 114]) -> Scalar {
+        Scalar(decode_long(bytes))
+    }
+
+    /// Decodes a 57-byte little-endian integer, returning `None` unless it
+    /// is below L.
+    pub fn from_canonical_bytes(bytes: &[u8; 57]) -> Option<Scalar> {
+        decode_canonical(bytes).ok().map(Scalar)
+    }
+
+    /// A uniformly random scalar.
+    pub fn random<R>(rng: &mut R) -> Scalar
+    where
+        R: Rng + CryptoRng,
+    {
+        let mut wide: [u8; 114] = [0; 114];
+        rng.fill_bytes(&mut wide);
+        let s = Scalar::from_bytes_mod_order_wide(&wide);
+
+        wide.zeroize();
+        s
+    }
+
+    /// The 57-byte little-endian encoding, as in the S half of a signature.
+    pub fn to_bytes(&self) -> [u8; 57] {
+        encode(&self.0)
+    }
+
+    /// The multiplicative inverse, or `None` for zero.
+    ///
+    /// The inverse is computed whatever the input, so only the returned
+    /// variant depends on whether the scalar is zero.
+    pub fn invert(&self) -> Option<Scalar> {
+        let inverse = Scalar(scalar::invert(&self.0));
+        let words = self.0.iter().fold(0, |acc, word| acc | word);
+        if is_zero_mask(words) == DECAF_TRUE {
+            return None;
+        }
+        Some(inverse)
+    }
+}
+
+impl fmt::Debug for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scalar").finish_non_exhaustive()
+    }
+}
+
+impl Default for Scalar {
+    fn default() -> Self {
+        Scalar::ZERO
+    }
+}
+
+impl From<u64> for Scalar {
+    fn from(n: u64) -> Self {
+        let mut s = scalar::create_zero_scalar();
+        s[0] = n as u32;
+        s[1] = (n >> 32) as u32;
+        Scalar(s)
+    }
+}
+
+// Both sides are always fully reduced, so comparing the words is enough.
+// Every word is looked at whatever the result.
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        let mut diff = 0;
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+}
+
+impl Eq for Scalar {}
+
+impl Add for Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: Scalar) -> Scalar {
+        Scalar(scalar::add(&self.0, &other.0))
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Scalar;
+
+    fn sub(self, other: Scalar) -> Scalar {
+        Scalar(scalar::sub(&self.0, &other.0))
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: Scalar) -> Scalar {
+        Scalar(scalar::mul(&self.0, &other.0))
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Scalar;
+
+    fn neg(self) -> Scalar {
+        Scalar::ZERO - self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants32::SCALAR_Q;
+
+    #[test]
+    pub fn test_scalar_arithmetic() {
+        let a = Scalar::from(0x1234_5678_9abc_def0);
+        let b = Scalar::random(&mut rand::thread_rng());
+
+        assert_eq!(a + b - b, a);
+        assert_eq!(a + (-a), Scalar::ZERO);
+        assert_eq!(-Scalar::ONE + Scalar::ONE, Scalar::ZERO);
+        assert_eq!(Scalar::from(6) * Scalar::from(7), Scalar::from(42));
+        assert_eq!(a * b, b * a);
+        assert_eq!(a * b.invert().unwrap() * b, a);
+        assert_eq!(Scalar::ONE.invert(), Some(Scalar::ONE));
+        assert_eq!((-Scalar::ONE).invert(), Some(-Scalar::ONE));
+        assert_eq!(Scalar::ZERO.invert(), None);
+        assert_ne!(a, b);
+        assert_eq!(format!("{a:?}"), "Scalar { .. }");
+    }
+
+    #[test]
+    pub fn test_scalar_encoding() {
+        let l = encode(&SCALAR_Q);
+        let l_minus_one = (-Scalar::ONE).to_bytes();
+        assert_eq!(Scalar::from_canonical_bytes(&l), None);
+        assert_eq!(Scalar::from_canonical_bytes(&[0xff; 57]), None);
+        assert_eq!(
+            Scalar::from_canonical_bytes(&l_minus_one),
+            Some(-Scalar::ONE)
+        );
+        assert_eq!(Scalar::from_bytes_mod_order(&l), Scalar::ZERO);
+
+        // 2^456 = 2^(8 * 57), a wide input past the 57-byte range.
+        let mut wide: [u8; 114] = [0; 114];
+        wide[57] = 1;
+        let mut shift = Scalar::ONE;
+        for _ in 0..456 {
+            shift = shift + shift;
+        }
+        assert_eq!(Scalar::from_bytes_mod_order_wide(&wide), shift);
+
+        let mut narrow: [u8; 114] = [0; 114];
+        narrow[..57].copy_from_slice(&l_minus_one);
+        assert_eq!(Scalar::from_bytes_mod_order_wide(&narrow), -Scalar::ONE);
+        wide[..57].copy_from_slice(&l_minus_one);
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide(&wide),
+            shift - Scalar::ONE
+        );
+
+        let s = Scalar::random(&mut rand::thread_rng());
+        assert_eq!(Scalar::from_canonical_bytes(&s.to_bytes()), Some(s));
+    }
+}
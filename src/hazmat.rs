@@ -0,0 +1,229 @@
+//! The individual steps of Ed448 signing and verification, for protocols
+//! that assemble signatures themselves (threshold signing, adaptor
+//! signatures). Nothing here stops a caller from reusing a nonce or signing
+//! with a challenge that does not match the message; doing either leaks the
+//! secret key.
+//!
+//! Scalars are 57-byte little-endian encodings below the group order L, as
+//! in the S half of a signature.
+
+use zeroize::Zeroize;
+
+use crate::eddsa::{
+    check_context, check_prehash, clamp, decode_pubkey, hash_with_dom, pubkey_wnaf_table,
+    verify_with_challenge, PreparedPubkey,
+};
+use crate::errors::LibgoldilockErrors;
+use crate::extended_point::precomputed_scalar_mul;
+use crate::scalar::{self, decode_canonical, decode_long, encode, halve};
+use crate::{ExpandedSecretKey, VerificationPolicy, VerifyingKey};
+
+pub use crate::field::FieldElement;
+
+// SHAKE256(dom4 || head || M, 114) reduced mod L.
+fn hash_to_scalar(head: &[&[u8]], message: &[u8], prehashed: bool, context: &[u8]) -> [u8; 57] {
+    let mut digest: [u8; 114] = [0; 114];
+    hash_with_dom(head, &[message], &mut digest, prehashed, context);
+    encode(&decode_long(&digest))
+}
+
+/// The RFC 8032 challenge k = SHAKE256(dom4(0, context) || R || A || M) mod L.
+///
+/// `r` and `public` are hashed as given. A signature is only sound if `r` is
+/// the commitment to the nonce that goes into `response` and `public` is the
+/// key whose secret does.
+pub fn challenge(
+    r: &[u8; 57],
+    public: &VerifyingKey,
+    message: &[u8],
+    context: &[u8],
+) -> Result<[u8; 57], LibgoldilockErrors> {
+    check_context(context)?;
+    Ok(hash_to_scalar(&[r, &public.key], message, false, context))
+}
+
+/// The Ed448ph challenge, over PH(M) and dom4(1, context). The same
+/// caveats as for `challenge` apply.
+pub fn challenge_prehashed(
+    r: &[u8; 57],
+    public: &VerifyingKey,
+    prehash: &[u8],
+    context: &[u8],
+) -> Result<[u8; 57], LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    check_context(context)?;
+    Ok(hash_to_scalar(&[r, &public.key], prehash, true, context))
+}
+
+/// The deterministic RFC 8032 nonce r = SHAKE256(dom4 || prefix || M) mod L.
+///
+/// The nonce is as secret as the key: publishing it, or using it in
+/// responses to two different challenges, reveals the secret scalar. It is
+/// only safe to reuse for the same message, context and key.
+pub fn derive_nonce(
+    secret: &ExpandedSecretKey,
+    message: &[u8],
+    context: &[u8],
+) -> Result<[u8; 57], LibgoldilockErrors> {
+    check_context(context)?;
+    Ok(hash_to_scalar(&[&secret.prefix], message, false, context))
+}
+
+/// The Ed448ph nonce, over PH(M) and dom4(1, context). The same caveats as
+/// for `derive_nonce` apply.
+pub fn derive_nonce_prehashed(
+    secret: &ExpandedSecretKey,
+    prehash: &[u8],
+    context: &[u8],
+) -> Result<[u8; 57], LibgoldilockErrors> {
+    check_prehash(prehash)?;
+    check_context(context)?;
+    Ok(hash_to_scalar(&[&secret.prefix], prehash, true, context))
+}
+
+/// The commitment R = [r]B to a nonce, which must be below L.
+///
+/// R is public, but a nonce committed to here must be used in at most one
+/// response.
+pub fn nonce_commitment(nonce: &[u8; 57]) -> Result<[u8; 57], LibgoldilockErrors> {
+    let mut r = decode_canonical(nonce)?;
+    let mut quarter = halve(halve(r));
+    let commitment = precomputed_scalar_mul(quarter).eddsa_like_encode();
+
+    r.zeroize();
+    quarter.zeroize();
+    Ok(commitment)
+}
+
+/// S = r + k * s for the clamped secret scalar s.
+///
+/// Nothing ties `nonce` or `challenge` to a message. Answering two
+/// different challenges with the same nonce reveals the secret scalar, as
+/// does a nonce an attacker can predict or bias.
+pub fn response(
+    secret: &ExpandedSecretKey,
+    nonce: &[u8; 57],
+    challenge: &[u8; 57],
+) -> Result<[u8; 57], LibgoldilockErrors> {
+    let k = decode_canonical(challenge)?;
+    let mut r = decode_canonical(nonce)?;
+    let mut secret_bytes = secret.scalar;
+    clamp(&mut secret_bytes);
+    let mut s = decode_long(&secret_bytes);
+
+    let mut ks = scalar::mul(&k, &s);
+    let response = encode(&scalar::add(&ks, &r));
+
+    secret_bytes.zeroize();
+    s.zeroize();
+    r.zeroize();
+    ks.zeroize();
+    Ok(response)
+}
+
+/// Checks [S]B = R + [k]A for a caller-supplied challenge, under the same
+/// decoding rules and `policy` as `VerifyingKey::verify_with_policy`.
+///
+/// Success only means the equation holds for `challenge`. The caller must
+/// check that the challenge was derived from R, the key and the message, or
+/// anyone can produce a passing (R, S, k) for any key.
+pub fn verify_response(
+    public: &VerifyingKey,
+    r: &[u8; 57],
+    s: &[u8; 57],
+    challenge: &[u8; 57],
+    policy: &VerificationPolicy,
+) -> Result<(), LibgoldilockErrors> {
+    decode_canonical(challenge)?;
+    let point = decode_pubkey(&public.key)?;
+    let prepared = PreparedPubkey {
+        table: pubkey_wnaf_table(&point),
+        point,
+        torsion: None,
+    };
+
+    let mut sig: [u8; 114] = [0; 114];
+    sig[..57].copy_from_slice(r);
+    sig[57..].copy_from_slice(s);
+    if !verify_with_challenge(&public.key, &prepared, &sig, challenge, policy)? {
+        return Err(LibgoldilockErrors::InvalidSignatureError);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goldilocks::ed448ph_prehash;
+    use crate::{Signature, SigningKey};
+
+    #[test]
+    pub fn test_hazmat_matches_signing() {
+        let key = SigningKey::random(&mut rand::thread_rng());
+        let expanded = key.secret_key().expand();
+        let public = key.verifying_key();
+        let message = b"assembled by hand";
+
+        let nonce = derive_nonce(&expanded, message, b"ctx").unwrap();
+        let r = nonce_commitment(&nonce).unwrap();
+        let k = challenge(&r, public, message, b"ctx").unwrap();
+        let s = response(&expanded, &nonce, &k).unwrap();
+        let sig = Signature::from_components(&r, &s).unwrap();
+        assert_eq!(sig, key.sign_with_context(message, b"ctx").unwrap());
+        for policy in [VerificationPolicy::RFC8032, VerificationPolicy::STRICT] {
+            assert!(verify_response(public, &r, &s, &k, &policy).is_ok());
+        }
+
+        let other = challenge(&r, public, b"another message", b"ctx").unwrap();
+        assert!(matches!(
+            verify_response(public, &r, &s, &other, &VerificationPolicy::RFC8032),
+            Err(LibgoldilockErrors::InvalidSignatureError)
+        ));
+
+        let prehash = ed448ph_prehash(message);
+        let nonce = derive_nonce_prehashed(&expanded, &prehash, &[]).unwrap();
+        let r = nonce_commitment(&nonce).unwrap();
+        let k = challenge_prehashed(&r, public, &prehash, &[]).unwrap();
+        let s = response(&expanded, &nonce, &k).unwrap();
+        let sig = Signature::from_components(&r, &s).unwrap();
+        assert_eq!(sig, key.sign_prehashed_with_context(&prehash, &[]).unwrap());
+    }
+
+    #[test]
+    pub fn test_hazmat_external_nonce() {
+        // Two nonces combine like their commitments: a response with r1 + r2
+        // verifies under R = [r1 + r2]B.
+        let key = SigningKey::random(&mut rand::thread_rng());
+        let expanded = key.secret_key().expand();
+        let public = key.verifying_key();
+
+        let r1 = derive_nonce(&expanded, b"first", &[]).unwrap();
+        let r2 = derive_nonce(&expanded, b"second", &[]).unwrap();
+        let nonce = encode(&scalar::add(
+            &decode_canonical(&r1).unwrap(),
+            &decode_canonical(&r2).unwrap(),
+        ));
+        let r = nonce_commitment(&nonce).unwrap();
+        let k = challenge(&r, public, b"joint", &[]).unwrap();
+        let s = response(&expanded, &nonce, &k).unwrap();
+        assert!(verify_response(public, &r, &s, &k, &VerificationPolicy::STRICT).is_ok());
+        assert!(public
+            .verify(b"joint", &Signature::from_components(&r, &s).unwrap())
+            .is_ok());
+
+        let mut high = [0u8; 57];
+        high[56] = 1;
+        assert!(matches!(
+            response(&expanded, &high, &k),
+            Err(LibgoldilockErrors::NonCanonicalScalarError)
+        ));
+        assert!(matches!(
+            nonce_commitment(&[0xff; 57]),
+            Err(LibgoldilockErrors::NonCanonicalScalarError)
+        ));
+        assert!(matches!(
+            challenge(&r, public, b"joint", &[0; 256]),
+            Err(LibgoldilockErrors::ContextTooLongError { .. })
+        ));
+    }
+}
@@ -1,117 +1,618 @@
-#![allow(clippy::should_implement_trait)]
+// These modules are kept as upstream wrote them, including lints that
+// newer toolchains report on their code and tests.
+#[allow(clippy::bool_assert_comparison, clippy::clone_on_copy)]
 mod bignumber;
+#[allow(clippy::manual_div_ceil)]
 mod constants32;
+mod decaf;
 mod decaf_combs_32;
 mod decaf_wnaf_table;
 mod eddsa;
+mod edwards;
 pub mod errors;
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::manual_is_multiple_of,
+    clippy::clone_on_copy
+)]
 mod extended_point;
+#[cfg(feature = "hazmat")]
+mod field;
 pub mod goldilocks;
+mod group_scalar;
+#[cfg(feature = "hazmat")]
+pub mod hazmat;
 mod karatsuba_32;
 mod karatsuba_square_32;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[allow(unused_assignments, clippy::needless_borrow)]
 mod scalar;
+pub mod streaming;
+
+pub use decaf::Decaf448Point;
+pub use edwards::EdwardsPoint;
+pub use group_scalar::Scalar;
 
 use crate::errors::LibgoldilockErrors;
-use goldilocks::{ed448_derive_public, ed448_sign, hex_to_private_key};
+use eddsa::{
+    check_prehash, check_signature_encoding, decode_pubkey, dsa_verify_batch, dsa_verify_prepared,
+    dsa_verify_with_policy, is_small_order, is_torsion_free, prepare_pubkey, validate_pubkey,
+    PreparedPubkey,
+};
+use goldilocks::{
+    ed448_derive_public, ed448_derive_public_expanded, ed448_sign_ctx, ed448_sign_expanded,
+    ed448_sign_hardened, ed448_sign_hedged, ed448_sign_hedged_hardened, ed448ph_sign,
+    ed448ph_sign_expanded, ed448ph_sign_hardened, ed448ph_sign_hedged,
+    ed448ph_sign_hedged_hardened, expand_seed, hex_to_private_key,
+};
 use rand::{CryptoRng, Rng};
+use sha3::digest::FixedOutput;
+use signature::rand_core::CryptoRngCore;
+use signature::{
+    DigestSigner, DigestVerifier, Keypair, RandomizedDigestSigner, RandomizedSigner,
+    SignatureEncoding, Signer, Verifier,
+};
+use std::fmt;
+use std::str::FromStr;
+use streaming::Shake256Digest;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 pub trait PrehashSigner<S> {
     fn sign_prehash(&self, prehash: &[u8]) -> Result<S, LibgoldilockErrors>;
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+pub trait PrehashVerifier<S> {
+    fn verify_prehash(&self, prehash: &[u8], signature: &S) -> Result<(), LibgoldilockErrors>;
+}
+
+/// An RFC 8032 seed. It is wiped when dropped, and `Debug` leaves it out.
+#[derive(Clone, Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
 pub struct SecretKey {
     key: [u8; 57],
 }
 
+/// An RFC 8032 secret key after hashing: the two halves of
+/// SHAKE256(seed, 114), the secret scalar bytes (clamped when used) and the
+/// prefix the nonces are derived from.
+#[derive(Clone, Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
+pub struct ExpandedSecretKey {
+    scalar: [u8; 57],
+    prefix: [u8; 57],
+}
+
+/// Rules applied when verifying a signature.
+///
+/// With `cofactored` set the group equation is checked as
+/// [4][S]B = [4]R + [4][k]A, as RFC 8032 specifies; otherwise the
+/// cofactorless equation [S]B = R + [k]A must hold exactly.
+/// `reject_small_order` rejects public keys and R values whose order
+/// divides the cofactor. Non-canonical encodings of A, R and S are rejected
+/// under every policy.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct VerificationPolicy {
+    pub cofactored: bool,
+    pub reject_small_order: bool,
+}
+
+impl VerificationPolicy {
+    /// RFC 8032, section 5.2.7: cofactored equation, small-order points
+    /// accepted. This is what `goldilocks::ed448_verify` applies.
+    pub const RFC8032: Self = Self {
+        cofactored: true,
+        reject_small_order: false,
+    };
+
+    /// Cofactorless equation and no small-order public key or R. Every
+    /// signature accepted here is accepted by `RFC8032`.
+    pub const STRICT: Self = Self {
+        cofactored: false,
+        reject_small_order: true,
+    };
+}
+
+impl Default for VerificationPolicy {
+    fn default() -> Self {
+        Self::RFC8032
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct VerifyingKey {
     key: [u8; 57],
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// A `VerifyingKey` with its decoded point and wNAF table computed once, for
+/// verifying many signatures under the same key.
+#[derive(Debug, Clone)]
+pub struct PreparedVerifyingKey {
+    verifying_key: VerifyingKey,
+    prepared: PreparedPubkey,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
 pub struct SigningKey {
     secret_key: SecretKey,
+    #[zeroize(skip)]
     verifying_key: VerifyingKey,
+    hardened: bool,
 }
 
+/// A standard 114-byte Ed448 signature, R || S.
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Signature {
+    sig: [u8; 114],
+}
+
+/// A signature followed by the verifying key it was made with, 171 bytes in
+/// all.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignatureWithKey {
     sig: [u8; 171],
 }
 
 impl SecretKey {
-    pub fn from_str(str: &str) -> Self {
-        let key = hex_to_private_key(str);
+    /// The 57-byte seed, as generated by RFC 8032 implementations.
+    pub fn from_bytes(bytes: &[u8; 57]) -> Self {
+        Self { key: *bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 57] {
+        &self.key
+    }
+
+    pub fn expand(&self) -> ExpandedSecretKey {
+        ExpandedSecretKey::from(self)
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey {
+            key: ed448_derive_public(&self.key),
+        }
+    }
+}
+
+impl From<&SecretKey> for ExpandedSecretKey {
+    fn from(s: &SecretKey) -> ExpandedSecretKey {
+        let (scalar, prefix) = expand_seed(&s.key);
+
+        ExpandedSecretKey { scalar, prefix }
+    }
+}
+
+impl ExpandedSecretKey {
+    /// The secret scalar bytes followed by the prefix.
+    pub fn from_bytes(bytes: &[u8; 114]) -> Self {
+        let mut scalar: [u8; 57] = [0; 57];
+        let mut prefix: [u8; 57] = [0; 57];
+        scalar.copy_from_slice(&bytes[..57]);
+        prefix.copy_from_slice(&bytes[57..]);
+
+        Self { scalar, prefix }
+    }
+
+    pub fn to_bytes(&self) -> [u8; 114] {
+        let mut bytes: [u8; 114] = [0; 114];
+        bytes[..57].copy_from_slice(&self.scalar);
+        bytes[57..].copy_from_slice(&self.prefix);
+        bytes
+    }
+
+    pub fn scalar_bytes(&self) -> &[u8; 57] {
+        &self.scalar
+    }
+
+    pub fn prefix(&self) -> &[u8; 57] {
+        &self.prefix
+    }
 
-        Self { key }
+    pub fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey {
+            key: ed448_derive_public_expanded(&self.scalar),
+        }
+    }
+
+    pub fn sign_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+    ) -> Result<Signature, LibgoldilockErrors> {
+        let sig = ed448_sign_expanded(&self.scalar, &self.prefix, message, context)?;
+
+        Ok(Signature { sig })
+    }
+
+    pub fn sign_prehashed_with_context(
+        &self,
+        prehash: &[u8],
+        context: &[u8],
+    ) -> Result<Signature, LibgoldilockErrors> {
+        let sig = ed448ph_sign_expanded(&self.scalar, &self.prefix, prehash, context)?;
+
+        Ok(Signature { sig })
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretKey").finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for ExpandedSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpandedSecretKey").finish_non_exhaustive()
+    }
+}
+
+impl FromStr for SecretKey {
+    type Err = LibgoldilockErrors;
+
+    fn from_str(s: &str) -> Result<Self, LibgoldilockErrors> {
+        let key = hex_to_private_key(s)?;
+
+        Ok(Self { key })
+    }
+}
+
+impl TryFrom<&[u8]> for SecretKey {
+    type Error = LibgoldilockErrors;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        let key: [u8; 57] =
+            bytes
+                .try_into()
+                .map_err(|_| LibgoldilockErrors::InvalidLengthError {
+                    expected: 57,
+                    actual: bytes.len(),
+                })?;
+
+        Ok(Self { key })
+    }
+}
+
+impl TryFrom<&[u8]> for ExpandedSecretKey {
+    type Error = LibgoldilockErrors;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        let bytes: &[u8; 114] =
+            bytes
+                .try_into()
+                .map_err(|_| LibgoldilockErrors::InvalidLengthError {
+                    expected: 114,
+                    actual: bytes.len(),
+                })?;
+
+        Ok(Self::from_bytes(bytes))
     }
 }
 
 impl From<SecretKey> for SigningKey {
     fn from(s: SecretKey) -> SigningKey {
-        SigningKey::from_slice(&s.key)
+        SigningKey::from_secret_key(s)
     }
 }
 
-impl VerifyingKey {
-    pub fn from_str(str: &str) -> Self {
-        let key = hex_to_private_key(str);
+impl FromStr for VerifyingKey {
+    type Err = LibgoldilockErrors;
+
+    fn from_str(s: &str) -> Result<Self, LibgoldilockErrors> {
+        let key = hex_to_private_key(s).map_err(|err| match err {
+            LibgoldilockErrors::InvalidLengthError { expected, actual } => {
+                LibgoldilockErrors::InvalidPubkeyLengthError { expected, actual }
+            }
+            err => err,
+        })?;
+
+        Ok(Self { key })
+    }
+}
+
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = LibgoldilockErrors;
 
-        Self { key }
+    fn try_from(bytes: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        let key: [u8; 57] =
+            bytes
+                .try_into()
+                .map_err(|_| LibgoldilockErrors::InvalidPubkeyLengthError {
+                    expected: 57,
+                    actual: bytes.len(),
+                })?;
+
+        Ok(Self { key })
+    }
+}
+
+impl VerifyingKey {
+    /// Wraps an encoded public key; it is decoded when first used.
+    pub fn from_bytes(bytes: &[u8; 57]) -> Self {
+        Self { key: *bytes }
     }
 
     pub fn as_bytes(&self) -> &[u8] {
         &self.key
     }
+
+    /// Whether the key is one of the points whose order divides the cofactor.
+    pub fn is_small_order(&self) -> Result<bool, LibgoldilockErrors> {
+        Ok(is_small_order(&decode_pubkey(&self.key)?))
+    }
+
+    /// Whether the key lies in the prime-order subgroup.
+    pub fn is_torsion_free(&self) -> Result<bool, LibgoldilockErrors> {
+        decode_pubkey(&self.key)?;
+        is_torsion_free(&self.key)
+    }
+
+    pub fn prepare(&self) -> Result<PreparedVerifyingKey, LibgoldilockErrors> {
+        PreparedVerifyingKey::new(self)
+    }
+
+    /// Checks a key received from an untrusted peer: it must be canonically
+    /// encoded, on the curve, not of small order and torsion free.
+    pub fn validate(&self) -> Result<(), LibgoldilockErrors> {
+        validate_pubkey(&self.key)
+    }
+
+    /// Verifies a signature under `VerificationPolicy::RFC8032`.
+    pub fn verify<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_with_policy(message, signature, &[], &VerificationPolicy::RFC8032)
+    }
+
+    /// Verifies a signature under `VerificationPolicy::STRICT`.
+    pub fn verify_strict<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_with_policy(message, signature, &[], &VerificationPolicy::STRICT)
+    }
+
+    /// Verifies a signature made with `context` under
+    /// `VerificationPolicy::RFC8032`.
+    pub fn verify_with_context<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+        context: &[u8],
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_with_policy(message, signature, context, &VerificationPolicy::RFC8032)
+    }
+
+    /// Verifies a signature made with `context` under the caller-supplied
+    /// `policy`.
+    pub fn verify_with_policy<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+        context: &[u8],
+        policy: &VerificationPolicy,
+    ) -> Result<(), LibgoldilockErrors> {
+        let signature = signature.as_ref();
+        if !dsa_verify_with_policy(&self.key, signature, &[message], false, context, policy)? {
+            return Err(LibgoldilockErrors::InvalidSignatureError);
+        }
+        Ok(())
+    }
+
+    /// Verifies an Ed448ph signature of a 64-byte prehash under
+    /// `VerificationPolicy::RFC8032`.
+    pub fn verify_prehashed<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        prehash: &[u8],
+        signature: &S,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_prehashed_with_context(prehash, signature, &[])
+    }
+
+    /// Verifies an Ed448ph signature of a 64-byte prehash made with
+    /// `context` under `VerificationPolicy::RFC8032`.
+    pub fn verify_prehashed_with_context<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        prehash: &[u8],
+        signature: &S,
+        context: &[u8],
+    ) -> Result<(), LibgoldilockErrors> {
+        check_prehash(prehash)?;
+        let signature = signature.as_ref();
+        let policy = VerificationPolicy::RFC8032;
+        if !dsa_verify_with_policy(&self.key, signature, &[prehash], true, context, &policy)? {
+            return Err(LibgoldilockErrors::InvalidSignatureError);
+        }
+        Ok(())
+    }
 }
 
-impl SigningKey {
-    pub fn from_str(str: &str) -> Self {
-        let private_key = hex_to_private_key(str);
-        let public_key = ed448_derive_public(&private_key);
-        let secret_key = SecretKey { key: private_key };
-        let verifying_key = VerifyingKey { key: public_key };
+impl PreparedVerifyingKey {
+    pub fn new(verifying_key: &VerifyingKey) -> Result<Self, LibgoldilockErrors> {
+        Ok(Self {
+            verifying_key: verifying_key.clone(),
+            prepared: prepare_pubkey(&verifying_key.key)?,
+        })
+    }
 
-        Self {
-            secret_key,
-            verifying_key,
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Verifies a signature under `VerificationPolicy::RFC8032`, using the
+    /// precomputed table.
+    pub fn verify<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_with_policy(message, signature, &[], &VerificationPolicy::RFC8032)
+    }
+
+    /// Verifies a signature under `VerificationPolicy::STRICT`.
+    pub fn verify_strict<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_with_policy(message, signature, &[], &VerificationPolicy::STRICT)
+    }
+
+    /// Verifies a signature made with `context` under
+    /// `VerificationPolicy::RFC8032`.
+    pub fn verify_with_context<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+        context: &[u8],
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_with_policy(message, signature, context, &VerificationPolicy::RFC8032)
+    }
+
+    /// Verifies a signature made with `context` under the caller-supplied
+    /// `policy`.
+    pub fn verify_with_policy<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        message: &[u8],
+        signature: &S,
+        context: &[u8],
+        policy: &VerificationPolicy,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_parts(&[message], signature.as_ref(), false, context, policy)
+    }
+
+    /// Verifies an Ed448ph signature of a 64-byte prehash under
+    /// `VerificationPolicy::RFC8032`.
+    pub fn verify_prehashed<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        prehash: &[u8],
+        signature: &S,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_prehashed_with_context(prehash, signature, &[])
+    }
+
+    /// Verifies an Ed448ph signature of a 64-byte prehash made with
+    /// `context` under `VerificationPolicy::RFC8032`.
+    pub fn verify_prehashed_with_context<S: AsRef<[u8]> + ?Sized>(
+        &self,
+        prehash: &[u8],
+        signature: &S,
+        context: &[u8],
+    ) -> Result<(), LibgoldilockErrors> {
+        check_prehash(prehash)?;
+        let policy = VerificationPolicy::RFC8032;
+        self.verify_parts(&[prehash], signature.as_ref(), true, context, &policy)
+    }
+
+    fn verify_parts(
+        &self,
+        message: &[&[u8]],
+        signature: &[u8],
+        prehashed: bool,
+        context: &[u8],
+        policy: &VerificationPolicy,
+    ) -> Result<(), LibgoldilockErrors> {
+        let key = &self.verifying_key.key;
+        if !dsa_verify_prepared(
+            key,
+            &self.prepared,
+            signature,
+            message,
+            prehashed,
+            context,
+            policy,
+        )? {
+            return Err(LibgoldilockErrors::InvalidSignatureError);
         }
+        Ok(())
+    }
+}
+
+impl TryFrom<&VerifyingKey> for PreparedVerifyingKey {
+    type Error = LibgoldilockErrors;
+
+    fn try_from(verifying_key: &VerifyingKey) -> Result<Self, Self::Error> {
+        Self::new(verifying_key)
+    }
+}
+
+/// Verifies many signatures with one multi-scalar multiplication, using
+/// random 128-bit weights drawn from `rng`.
+///
+/// The check is cofactored, as for `VerificationPolicy::RFC8032`. If it
+/// fails, every signature is verified on its own and the indices of the bad
+/// ones are returned in `LibgoldilockErrors::BatchVerificationError`.
+pub fn verify_batch<R>(
+    items: &[(VerifyingKey, &[u8], Signature)],
+    rng: &mut R,
+) -> Result<(), LibgoldilockErrors>
+where
+    R: Rng + CryptoRng,
+{
+    let triples: Vec<(&[u8], &[u8], &[u8])> = items
+        .iter()
+        .map(|(key, message, signature)| (key.as_bytes(), signature.as_ref(), *message))
+        .collect();
+    if let Ok(true) = dsa_verify_batch(&triples, rng) {
+        return Ok(());
+    }
+
+    let invalid: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, (key, message, signature))| key.verify(message, signature).is_err())
+        .map(|(i, _)| i)
+        .collect();
+    if invalid.is_empty() {
+        return Ok(());
+    }
+    Err(LibgoldilockErrors::BatchVerificationError(invalid))
+}
+
+impl FromStr for SigningKey {
+    type Err = LibgoldilockErrors;
+
+    fn from_str(s: &str) -> Result<Self, LibgoldilockErrors> {
+        Ok(Self::from_secret_key(SecretKey::from_str(s)?))
     }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = LibgoldilockErrors;
 
-    pub fn from_slice(s: &[u8]) -> Self {
-        let mut private_key: [u8; 57] = [0; 57];
-        private_key.copy_from_slice(s);
-        let public_key = ed448_derive_public(&private_key);
-        let secret_key = SecretKey { key: private_key };
-        let verifying_key = VerifyingKey { key: public_key };
+    fn try_from(bytes: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        Ok(Self::from_secret_key(SecretKey::try_from(bytes)?))
+    }
+}
+
+impl SigningKey {
+    fn from_secret_key(secret_key: SecretKey) -> Self {
+        let verifying_key = secret_key.verifying_key();
 
         Self {
             secret_key,
             verifying_key,
+            hardened: false,
         }
     }
 
-    pub fn from_bytes(s: &[u8]) -> Result<Self, LibgoldilockErrors> {
-        let mut private_key: [u8; 57] = [0; 57];
-        private_key.copy_from_slice(s);
-        let public_key = ed448_derive_public(&private_key);
-        let secret_key = SecretKey { key: private_key };
-        let verifying_key = VerifyingKey { key: public_key };
+    pub fn from_slice(s: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        Self::try_from(s)
+    }
 
-        Ok(Self {
-            secret_key,
-            verifying_key,
-        })
+    pub fn from_bytes(s: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        Self::try_from(s)
     }
 
     pub fn random<R>(rng: &mut R) -> Self
     where
         R: Rng + CryptoRng,
     {
-        let mut key: [u8; 57] = [0; 57];
-        rng.fill_bytes(key.as_mut_slice());
+        let mut secret_key = SecretKey { key: [0; 57] };
+        rng.fill_bytes(&mut secret_key.key);
 
-        SigningKey::from_slice(&key)
+        SigningKey::from_secret_key(secret_key)
     }
 
     pub fn verifying_key(&self) -> &VerifyingKey {
@@ -125,23 +626,909 @@ impl SigningKey {
     pub fn to_bytes(&self) -> [u8; 57] {
         self.secret_key.key
     }
+
+    /// Turns the fault-attack countermeasures on or off. A hardened key
+    /// recomputes every fixed-base multiplication through a second path,
+    /// checks the result against the cached verifying key and verifies each
+    /// signature before returning it; a disagreement is reported as
+    /// `FaultDetectedError` instead of releasing the signature.
+    pub fn with_hardening(mut self, hardened: bool) -> Self {
+        self.hardened = hardened;
+        self
+    }
+
+    pub fn is_hardened(&self) -> bool {
+        self.hardened
+    }
+
+    pub fn sign_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+    ) -> Result<Signature, LibgoldilockErrors> {
+        let key = &self.secret_key.key;
+        let sig = if self.hardened {
+            ed448_sign_hardened(key, &self.verifying_key.key, message, context)?
+        } else {
+            ed448_sign_ctx(key, message, context)?
+        };
+
+        Ok(Signature { sig })
+    }
+
+    pub fn sign_prehashed_with_context(
+        &self,
+        prehash: &[u8],
+        context: &[u8],
+    ) -> Result<Signature, LibgoldilockErrors> {
+        let key = &self.secret_key.key;
+        let sig = if self.hardened {
+            ed448ph_sign_hardened(key, &self.verifying_key.key, prehash, context)?
+        } else {
+            ed448ph_sign(key, prehash, context)?
+        };
+
+        Ok(Signature { sig })
+    }
+
+    /// Hedged counterpart of `sign_with_context`: output of `rng` is mixed
+    /// into the nonce, so faults and side channels cannot rely on the
+    /// signature being a deterministic function of the message.
+    pub fn sign_hedged_with_context<R>(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        rng: &mut R,
+    ) -> Result<Signature, LibgoldilockErrors>
+    where
+        R: Rng + CryptoRng,
+    {
+        let key = &self.secret_key.key;
+        let sig = if self.hardened {
+            ed448_sign_hedged_hardened(key, &self.verifying_key.key, message, context, rng)?
+        } else {
+            ed448_sign_hedged(key, message, context, rng)?
+        };
+
+        Ok(Signature { sig })
+    }
+
+    pub fn sign_prehashed_hedged_with_context<R>(
+        &self,
+        prehash: &[u8],
+        context: &[u8],
+        rng: &mut R,
+    ) -> Result<Signature, LibgoldilockErrors>
+    where
+        R: Rng + CryptoRng,
+    {
+        let key = &self.secret_key.key;
+        let sig = if self.hardened {
+            ed448ph_sign_hedged_hardened(key, &self.verifying_key.key, prehash, context, rng)?
+        } else {
+            ed448ph_sign_hedged(key, prehash, context, rng)?
+        };
+
+        Ok(Signature { sig })
+    }
 }
 
 impl PrehashSigner<Signature> for SigningKey {
     fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature, LibgoldilockErrors> {
-        let sig = ed448_sign(&self.secret_key.key, prehash);
-        let mut sig_with_private_key: [u8; 171] = [0; 171];
-        sig_with_private_key[0..114].copy_from_slice(&sig);
-        sig_with_private_key[114..171].copy_from_slice(&self.verifying_key.key);
+        self.sign_prehashed_with_context(prehash, &[])
+    }
+}
 
-        Ok(Signature {
-            sig: sig_with_private_key,
-        })
+impl PrehashVerifier<Signature> for VerifyingKey {
+    fn verify_prehash(
+        &self,
+        prehash: &[u8],
+        signature: &Signature,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_prehashed_with_context(prehash, signature, &[])
     }
 }
 
 impl Signature {
+    /// Parses R || S strictly: R must be a canonically encoded curve point
+    /// and S must be below the group order.
+    pub fn from_bytes(bytes: &[u8; 114]) -> Result<Self, LibgoldilockErrors> {
+        check_signature_encoding(bytes)?;
+
+        Ok(Signature { sig: *bytes })
+    }
+
+    pub fn from_components(r: &[u8; 57], s: &[u8; 57]) -> Result<Self, LibgoldilockErrors> {
+        let mut bytes: [u8; 114] = [0; 114];
+        bytes[..57].copy_from_slice(r);
+        bytes[57..].copy_from_slice(s);
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 114] {
+        self.sig
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         &self.sig
     }
+
+    /// The encoded nonce point R.
+    pub fn r_bytes(&self) -> [u8; 57] {
+        let mut r: [u8; 57] = [0; 57];
+        r.copy_from_slice(&self.sig[..57]);
+        r
+    }
+
+    /// The encoded response scalar S.
+    pub fn s_bytes(&self) -> [u8; 57] {
+        let mut s: [u8; 57] = [0; 57];
+        s.copy_from_slice(&self.sig[57..]);
+        s
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.sig
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = LibgoldilockErrors;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        let sig: &[u8; 114] =
+            bytes
+                .try_into()
+                .map_err(|_| LibgoldilockErrors::InvalidSignatureLengthError {
+                    expected: 114,
+                    actual: bytes.len(),
+                })?;
+
+        Signature::from_bytes(sig)
+    }
+}
+
+impl TryFrom<[u8; 114]> for Signature {
+    type Error = LibgoldilockErrors;
+
+    fn try_from(sig: [u8; 114]) -> Result<Self, LibgoldilockErrors> {
+        Signature::from_bytes(&sig)
+    }
+}
+
+impl From<Signature> for [u8; 114] {
+    fn from(s: Signature) -> [u8; 114] {
+        s.sig
+    }
+}
+
+impl SignatureEncoding for Signature {
+    type Repr = [u8; 114];
+}
+
+impl SignatureWithKey {
+    pub fn new(signature: &Signature, verifying_key: &VerifyingKey) -> Self {
+        let mut sig: [u8; 171] = [0; 171];
+        sig[..114].copy_from_slice(&signature.sig);
+        sig[114..].copy_from_slice(&verifying_key.key);
+
+        SignatureWithKey { sig }
+    }
+
+    /// Parses signature || public key, with the signature part parsed as
+    /// strictly as by `Signature::from_bytes`.
+    pub fn from_bytes(bytes: &[u8; 171]) -> Result<Self, LibgoldilockErrors> {
+        check_signature_encoding(&bytes[..114])?;
+
+        Ok(SignatureWithKey { sig: *bytes })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 171] {
+        self.sig
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.sig
+    }
+
+    pub fn signature(&self) -> Signature {
+        let mut sig: [u8; 114] = [0; 114];
+        sig.copy_from_slice(&self.sig[..114]);
+
+        Signature { sig }
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        let mut key: [u8; 57] = [0; 57];
+        key.copy_from_slice(&self.sig[114..]);
+
+        VerifyingKey { key }
+    }
+
+    /// Verifies against the embedded key. This only shows that the holder of
+    /// that key signed the message; use `verify_pinned` when the signer must
+    /// be a particular key.
+    pub fn verify(&self, message: &[u8]) -> Result<(), LibgoldilockErrors> {
+        self.verify_with_context(message, &[])
+    }
+
+    pub fn verify_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verifying_key()
+            .verify_with_context(message, &self.sig[..114], context)
+    }
+
+    /// Verifies against `expected`, rejecting a signature that embeds any
+    /// other key with `PubkeyMismatchError`.
+    pub fn verify_pinned(
+        &self,
+        message: &[u8],
+        expected: &VerifyingKey,
+    ) -> Result<(), LibgoldilockErrors> {
+        self.verify_pinned_with_context(message, &[], expected)
+    }
+
+    pub fn verify_pinned_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        expected: &VerifyingKey,
+    ) -> Result<(), LibgoldilockErrors> {
+        if self.sig[114..] != expected.key {
+            return Err(LibgoldilockErrors::PubkeyMismatchError);
+        }
+        expected.verify_with_context(message, &self.sig[..114], context)
+    }
+}
+
+impl AsRef<[u8]> for SignatureWithKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.sig
+    }
+}
+
+impl TryFrom<&[u8]> for SignatureWithKey {
+    type Error = LibgoldilockErrors;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        let sig: &[u8; 171] =
+            bytes
+                .try_into()
+                .map_err(|_| LibgoldilockErrors::InvalidSignatureLengthError {
+                    expected: 171,
+                    actual: bytes.len(),
+                })?;
+
+        SignatureWithKey::from_bytes(sig)
+    }
+}
+
+impl TryFrom<[u8; 171]> for SignatureWithKey {
+    type Error = LibgoldilockErrors;
+
+    fn try_from(sig: [u8; 171]) -> Result<Self, LibgoldilockErrors> {
+        SignatureWithKey::from_bytes(&sig)
+    }
+}
+
+impl From<SignatureWithKey> for [u8; 171] {
+    fn from(s: SignatureWithKey) -> [u8; 171] {
+        s.sig
+    }
+}
+
+impl SignatureEncoding for SignatureWithKey {
+    type Repr = [u8; 171];
+}
+
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, signature::Error> {
+        self.sign_with_context(msg, &[])
+            .map_err(signature::Error::from_source)
+    }
+}
+
+impl DigestSigner<Shake256Digest, Signature> for SigningKey {
+    fn try_sign_digest(&self, digest: Shake256Digest) -> Result<Signature, signature::Error> {
+        self.sign_prehash(&digest.finalize_fixed())
+            .map_err(signature::Error::from_source)
+    }
+}
+
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, signature::Error> {
+        self.sign_hedged_with_context(msg, &[], rng)
+            .map_err(signature::Error::from_source)
+    }
+}
+
+impl RandomizedDigestSigner<Shake256Digest, Signature> for SigningKey {
+    fn try_sign_digest_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        digest: Shake256Digest,
+    ) -> Result<Signature, signature::Error> {
+        self.sign_prehashed_hedged_with_context(&digest.finalize_fixed(), &[], rng)
+            .map_err(signature::Error::from_source)
+    }
+}
+
+impl Signer<SignatureWithKey> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<SignatureWithKey, signature::Error> {
+        let signature = self
+            .sign_with_context(msg, &[])
+            .map_err(signature::Error::from_source)?;
+
+        Ok(SignatureWithKey::new(&signature, &self.verifying_key))
+    }
+}
+
+impl Keypair for SigningKey {
+    type VerifyingKey = VerifyingKey;
+
+    fn verifying_key(&self) -> VerifyingKey {
+        self.verifying_key.clone()
+    }
+}
+
+impl Verifier<Signature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), signature::Error> {
+        self.verify_with_context(msg, signature, &[])
+            .map_err(signature::Error::from_source)
+    }
+}
+
+impl Verifier<Signature> for PreparedVerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), signature::Error> {
+        self.verify_with_context(msg, signature, &[])
+            .map_err(signature::Error::from_source)
+    }
+}
+
+// Verifying a signature with an embedded key under a given key pins it to
+// that key.
+impl Verifier<SignatureWithKey> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &SignatureWithKey) -> Result<(), signature::Error> {
+        signature
+            .verify_pinned(msg, self)
+            .map_err(signature::Error::from_source)
+    }
+}
+
+impl DigestVerifier<Shake256Digest, Signature> for VerifyingKey {
+    fn verify_digest(
+        &self,
+        digest: Shake256Digest,
+        signature: &Signature,
+    ) -> Result<(), signature::Error> {
+        self.verify_prehash(&digest.finalize_fixed(), signature)
+            .map_err(signature::Error::from_source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::digest::Update;
+
+    #[test]
+    pub fn test_sign_verify_with_context() {
+        let key = SigningKey::from_str("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let message = [0x03];
+        let sig = key.sign_with_context(&message, b"foo").unwrap();
+        let exp = goldilocks::hex_to_signature("d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d5428407e85dcbc98a49155c13764e66c3c00").unwrap();
+        assert_eq!(&sig.as_slice()[..114], &exp);
+
+        let verifying_key = key.verifying_key();
+        assert!(verifying_key
+            .verify_with_context(&message, sig.as_slice(), b"foo")
+            .is_ok());
+        assert!(matches!(
+            verifying_key.verify_with_context(&message, sig.as_slice(), b""),
+            Err(LibgoldilockErrors::InvalidSignatureError)
+        ));
+        assert!(matches!(
+            key.sign_with_context(&message, &[0; 256]),
+            Err(LibgoldilockErrors::ContextTooLongError {
+                max: 255,
+                actual: 256
+            })
+        ));
+    }
+
+    #[test]
+    pub fn test_verify_policies() {
+        let key = SigningKey::from_str("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let verifying_key = key.verifying_key();
+        let sig = key.sign_with_context(b"message", &[]).unwrap();
+        assert!(verifying_key.verify(b"message", &sig).is_ok());
+        assert!(verifying_key.verify_strict(b"message", &sig).is_ok());
+        assert!(matches!(
+            verifying_key.verify_strict(b"other", &sig),
+            Err(LibgoldilockErrors::InvalidSignatureError)
+        ));
+
+        let prehash = goldilocks::ed448ph_prehash(b"message");
+        let sig = key.sign_prehash(&prehash).unwrap();
+        assert!(verifying_key.verify_prehashed(&prehash, &sig).is_ok());
+        assert!(matches!(
+            verifying_key.verify_prehashed(&prehash[..32], &sig),
+            Err(LibgoldilockErrors::InvalidPrehashLengthError {
+                expected: 64,
+                actual: 32
+            })
+        ));
+
+        // The identity as public key: S = r verifies any message for R = [r]B.
+        let mut identity = [0u8; 57];
+        identity[0] = 1;
+        let verifying_key = VerifyingKey { key: identity };
+        let r_point = goldilocks::ed448_derive_public(&[0; 57]);
+        let mut sig = [0u8; 114];
+        sig[..57].copy_from_slice(&r_point);
+        let (r, _) = goldilocks::expand_seed(&[0; 57]);
+        sig[57..].copy_from_slice(&scalar::encode(&scalar::decode_long(&r)));
+        assert!(verifying_key.verify(b"message", &sig).is_ok());
+        assert!(matches!(
+            verifying_key.verify_strict(b"message", &sig),
+            Err(LibgoldilockErrors::SmallOrderPubkeyError)
+        ));
+    }
+
+    #[test]
+    pub fn test_verifying_key_validation() {
+        let key = SigningKey::from_str("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let verifying_key = key.verifying_key();
+        assert!(!verifying_key.is_small_order().unwrap());
+        assert!(verifying_key.is_torsion_free().unwrap());
+        assert!(verifying_key.validate().is_ok());
+
+        // (0, -1), the point of order two.
+        let mut key = [0xff; 57];
+        key[0] = 0xfe;
+        key[28] = 0xfe;
+        key[56] = 0;
+        let verifying_key = VerifyingKey { key };
+        assert!(verifying_key.is_small_order().unwrap());
+        assert!(!verifying_key.is_torsion_free().unwrap());
+        assert!(matches!(
+            verifying_key.validate(),
+            Err(LibgoldilockErrors::SmallOrderPubkeyError)
+        ));
+    }
+
+    #[test]
+    pub fn test_verify_batch() {
+        let mut rng = rand::thread_rng();
+        let messages: Vec<Vec<u8>> = (0..8u8).map(|i| vec![i; i as usize * 10]).collect();
+        let mut items: Vec<(VerifyingKey, &[u8], Signature)> = messages
+            .iter()
+            .map(|message| {
+                let key = SigningKey::random(&mut rng);
+                let sig = key.sign_with_context(message, &[]).unwrap();
+                (key.verifying_key().clone(), message.as_slice(), sig)
+            })
+            .collect();
+        assert!(verify_batch(&items, &mut rng).is_ok());
+        assert!(verify_batch(&[], &mut rng).is_ok());
+
+        // A signature over another message and a key with a reserved bit set.
+        items[2].1 = b"forged";
+        items[5].0.key[56] |= 0x01;
+        match verify_batch(&items, &mut rng) {
+            Err(LibgoldilockErrors::BatchVerificationError(invalid)) => {
+                assert_eq!(invalid, vec![2, 5])
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    pub fn test_prepared_verifying_key() {
+        let key = SigningKey::from_str("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let prepared = key.verifying_key().prepare().unwrap();
+        assert_eq!(prepared.verifying_key(), key.verifying_key());
+
+        for i in 0..4u8 {
+            let message = [i; 20];
+            let sig = key.sign_with_context(&message, &[]).unwrap();
+            assert!(prepared.verify(&message, &sig).is_ok());
+            assert!(prepared.verify_strict(&message, &sig).is_ok());
+            assert!(Verifier::verify(&prepared, &message, &sig).is_ok());
+            assert!(matches!(
+                prepared.verify(&[i; 21], &sig),
+                Err(LibgoldilockErrors::InvalidSignatureError)
+            ));
+
+            let sig = key.sign_with_context(&message, b"foo").unwrap();
+            assert!(prepared.verify_with_context(&message, &sig, b"foo").is_ok());
+            assert!(prepared.verify(&message, &sig).is_err());
+
+            let prehash = goldilocks::ed448ph_prehash(&message);
+            let sig = key.sign_prehash(&prehash).unwrap();
+            assert!(prepared.verify_prehashed(&prehash, &sig).is_ok());
+        }
+
+        let mut bad_key = key.verifying_key().clone();
+        bad_key.key[56] |= 0x01;
+        assert!(matches!(
+            PreparedVerifyingKey::try_from(&bad_key),
+            Err(LibgoldilockErrors::NonCanonicalPubkeyError)
+        ));
+    }
+
+    #[test]
+    pub fn test_sign_verify_prehash() {
+        let key = SigningKey::from_str("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49").unwrap();
+        let prehash = goldilocks::ed448ph_prehash(b"abc");
+        let sig = key.sign_prehash(&prehash).unwrap();
+        let exp = goldilocks::hex_to_signature("822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae381f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b801a0d9b3f4030cd433964b6457ea39476511214f97469b57dd32dbc560a9a94d00bff07620464a3ad203df7dc7ce360c3cd3696d9d9fab90f00").unwrap();
+        assert_eq!(sig.to_bytes(), exp);
+
+        let verifying_key = key.verifying_key();
+        assert!(verifying_key.verify_prehash(&prehash, &sig).is_ok());
+        assert!(matches!(
+            verifying_key.verify_prehash(&goldilocks::ed448ph_prehash(b"abd"), &sig),
+            Err(LibgoldilockErrors::InvalidSignatureError)
+        ));
+
+        let sig = key.sign_prehashed_with_context(&prehash, b"foo").unwrap();
+        assert!(verifying_key
+            .verify_prehashed_with_context(&prehash, sig.as_slice(), b"foo")
+            .is_ok());
+        assert!(verifying_key.verify_prehash(&prehash, &sig).is_err());
+        assert!(matches!(
+            key.sign_prehash(b"abc"),
+            Err(LibgoldilockErrors::InvalidPrehashLengthError {
+                expected: 64,
+                actual: 3
+            })
+        ));
+    }
+
+    fn sign_generic<K: Signer<S> + Keypair, S: SignatureEncoding>(key: &K, msg: &[u8]) -> Vec<u8> {
+        key.sign(msg).to_vec()
+    }
+
+    #[test]
+    pub fn test_signature_traits() {
+        let key = SigningKey::random(&mut rand::thread_rng());
+        let fox = b"The quick brown fox jumps over the lazy dog";
+
+        let sig: Signature = key.sign(fox);
+        assert_eq!(sig.to_bytes(), goldilocks::ed448_sign(&key.to_bytes(), fox));
+        let verifying_key = Keypair::verifying_key(&key);
+        assert!(Verifier::verify(&verifying_key, fox, &sig).is_ok());
+        assert!(Verifier::verify(&verifying_key, b"The quick brown fox", &sig).is_err());
+
+        let bytes = sig.to_bytes();
+        assert_eq!(Signature::try_from(&bytes[..]).unwrap(), sig);
+        assert_eq!(sign_generic::<_, Signature>(&key, fox), sig.to_vec());
+        assert!(matches!(
+            Signature::try_from(&bytes[..113]),
+            Err(LibgoldilockErrors::InvalidSignatureLengthError {
+                expected: 114,
+                actual: 113
+            })
+        ));
+
+        let with_key: SignatureWithKey = key.sign(fox);
+        assert_eq!(with_key.signature(), sig);
+        assert!(Verifier::verify(&verifying_key, fox, &with_key).is_ok());
+        assert_eq!(
+            sign_generic::<_, SignatureWithKey>(&key, fox),
+            with_key.to_vec()
+        );
+    }
+
+    #[test]
+    pub fn test_signature_parsing() {
+        let key = SigningKey::random(&mut rand::thread_rng());
+        let sig = key.sign_with_context(b"message", &[]).unwrap();
+        let bytes = sig.to_bytes();
+        assert_eq!(Signature::from_bytes(&bytes).unwrap(), sig);
+        assert_eq!(
+            Signature::from_components(&sig.r_bytes(), &sig.s_bytes()).unwrap(),
+            sig
+        );
+        assert_eq!(&bytes[..57], &sig.r_bytes());
+        assert_eq!(&bytes[57..], &sig.s_bytes());
+
+        let mut high_s = bytes;
+        high_s[113] = 1;
+        assert!(matches!(
+            Signature::from_bytes(&high_s),
+            Err(LibgoldilockErrors::NonCanonicalScalarError)
+        ));
+        let mut reserved = bytes;
+        reserved[56] |= 0x01;
+        assert!(matches!(
+            Signature::from_bytes(&reserved),
+            Err(LibgoldilockErrors::NonCanonicalSignatureError)
+        ));
+        let mut long = bytes.to_vec();
+        long.push(0);
+        assert!(matches!(
+            key.verifying_key().verify(b"message", &long),
+            Err(LibgoldilockErrors::InvalidSignatureLengthError {
+                expected: 114,
+                actual: 115
+            })
+        ));
+    }
+
+    #[test]
+    pub fn test_signature_with_key() {
+        let mut rng = rand::thread_rng();
+        let key = SigningKey::random(&mut rng);
+        let other = SigningKey::random(&mut rng);
+        let sig = key.sign_with_context(b"message", b"ctx").unwrap();
+
+        let with_key = SignatureWithKey::new(&sig, key.verifying_key());
+        assert_eq!(&with_key.as_slice()[..114], sig.as_slice());
+        assert_eq!(&with_key.as_slice()[114..], key.verifying_key().as_bytes());
+        assert_eq!(&with_key.verifying_key(), key.verifying_key());
+        assert_eq!(
+            SignatureWithKey::try_from(with_key.as_slice()).unwrap(),
+            with_key
+        );
+
+        assert!(with_key.verify_with_context(b"message", b"ctx").is_ok());
+        assert!(with_key.verify(b"message").is_err());
+        assert!(with_key
+            .verify_pinned_with_context(b"message", b"ctx", key.verifying_key())
+            .is_ok());
+        assert!(matches!(
+            with_key.verify_pinned_with_context(b"message", b"ctx", other.verifying_key()),
+            Err(LibgoldilockErrors::PubkeyMismatchError)
+        ));
+
+        // A forger can always embed their own key; pinning catches it.
+        let forged = SignatureWithKey::new(
+            &other.sign_with_context(b"message", b"ctx").unwrap(),
+            other.verifying_key(),
+        );
+        assert!(forged.verify_with_context(b"message", b"ctx").is_ok());
+        assert!(matches!(
+            forged.verify_pinned_with_context(b"message", b"ctx", key.verifying_key()),
+            Err(LibgoldilockErrors::PubkeyMismatchError)
+        ));
+        assert!(matches!(
+            SignatureWithKey::try_from(sig.as_slice()),
+            Err(LibgoldilockErrors::InvalidSignatureLengthError {
+                expected: 171,
+                actual: 114
+            })
+        ));
+    }
+
+    #[test]
+    pub fn test_digest_signature_traits() {
+        let key = SigningKey::from_str("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49").unwrap();
+        let mut digest = Shake256Digest::new();
+        digest.update(b"ab");
+        digest.update(b"c");
+        assert_eq!(
+            digest.clone().finalize_fixed().as_slice(),
+            &goldilocks::ed448ph_prehash(b"abc")
+        );
+
+        let sig: Signature = key.sign_digest(digest.clone());
+        let exp = goldilocks::hex_to_signature("822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae381f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b801a0d9b3f4030cd433964b6457ea39476511214f97469b57dd32dbc560a9a94d00bff07620464a3ad203df7dc7ce360c3cd3696d9d9fab90f00").unwrap();
+        assert_eq!(&sig.as_slice()[..114], &exp);
+        assert!(key.verifying_key().verify_digest(digest, &sig).is_ok());
+
+        let mut other = Shake256Digest::new();
+        other.update(b"abd");
+        assert!(key.verifying_key().verify_digest(other, &sig).is_err());
+    }
+
+    #[test]
+    pub fn test_hedged_signing() {
+        let mut rng = rand::thread_rng();
+        let key = SigningKey::random(&mut rng);
+        let message = b"hedged";
+
+        let deterministic = key.sign_with_context(message, b"ctx").unwrap();
+        let first = key
+            .sign_hedged_with_context(message, b"ctx", &mut rng)
+            .unwrap();
+        let second = key
+            .sign_hedged_with_context(message, b"ctx", &mut rng)
+            .unwrap();
+        assert_ne!(first, deterministic);
+        assert_ne!(first, second);
+        for sig in [&first, &second] {
+            assert!(key
+                .verifying_key()
+                .verify_with_context(message, sig, b"ctx")
+                .is_ok());
+        }
+
+        let sig: Signature = key.sign_with_rng(&mut rng, message);
+        assert!(Verifier::verify(key.verifying_key(), message, &sig).is_ok());
+
+        let mut digest = Shake256Digest::new();
+        digest.update(message);
+        let sig: Signature = key.sign_digest_with_rng(&mut rng, digest.clone());
+        assert_ne!(sig, key.sign_digest(digest.clone()));
+        assert!(key.verifying_key().verify_digest(digest, &sig).is_ok());
+    }
+
+    #[test]
+    pub fn test_hardened_signing() {
+        let mut rng = rand::thread_rng();
+        let key = SigningKey::random(&mut rng);
+        let hardened = key.clone().with_hardening(true);
+        assert!(!key.is_hardened());
+        assert!(hardened.is_hardened());
+
+        let message = b"hardened";
+        let sig = hardened.sign_with_context(message, b"ctx").unwrap();
+        assert_eq!(sig, key.sign_with_context(message, b"ctx").unwrap());
+        let prehash = goldilocks::ed448ph_prehash(message);
+        let sig = hardened.sign_prehashed_with_context(&prehash, &[]).unwrap();
+        assert_eq!(sig, key.sign_prehashed_with_context(&prehash, &[]).unwrap());
+
+        let sig = hardened
+            .sign_hedged_with_context(message, &[], &mut rng)
+            .unwrap();
+        assert!(hardened.verifying_key().verify(message, &sig).is_ok());
+        let sig: Signature = hardened.sign(message);
+        assert!(Verifier::verify(hardened.verifying_key(), message, &sig).is_ok());
+
+        // A cached verifying key that no longer matches the secret is caught.
+        let mut corrupted = hardened.clone();
+        corrupted.verifying_key = SigningKey::random(&mut rng).verifying_key().clone();
+        assert!(matches!(
+            corrupted.sign_with_context(message, &[]),
+            Err(LibgoldilockErrors::FaultDetectedError)
+        ));
+        assert!(key
+            .with_hardening(false)
+            .sign_with_context(message, &[])
+            .is_ok());
+    }
+
+    #[test]
+    pub fn test_expanded_secret_key() {
+        // RFC 8032, section 7.4, "1 octet": the seed has its top bit clear,
+        // so force it on to make sure it is still read as a seed.
+        let mut seed = hex_to_private_key("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        let secret = SecretKey::from_bytes(&seed);
+        let expanded = secret.expand();
+        assert_eq!(expanded, ExpandedSecretKey::from(&secret));
+        assert_eq!(
+            ExpandedSecretKey::from_bytes(&expanded.to_bytes()),
+            expanded
+        );
+        assert_eq!(secret.verifying_key(), expanded.verifying_key());
+
+        let signing_key = SigningKey::from(secret.clone());
+        let sig = expanded.sign_with_context(&[0x03], &[]).unwrap();
+        assert_eq!(sig, signing_key.sign_with_context(&[0x03], &[]).unwrap());
+        let prehash = goldilocks::ed448ph_prehash(b"abc");
+        assert_eq!(
+            expanded
+                .sign_prehashed_with_context(&prehash, b"ctx")
+                .unwrap(),
+            signing_key
+                .sign_prehashed_with_context(&prehash, b"ctx")
+                .unwrap()
+        );
+
+        seed[56] |= 0x80;
+        let secret = SecretKey::from_bytes(&seed);
+        let signing_key = SigningKey::from(secret.clone());
+        assert_eq!(&secret.verifying_key(), signing_key.verifying_key());
+        let sig = signing_key.sign_with_context(b"msg", &[]).unwrap();
+        assert_eq!(sig, secret.expand().sign_with_context(b"msg", &[]).unwrap());
+        assert!(signing_key.verifying_key().verify(b"msg", &sig).is_ok());
+    }
+
+    #[test]
+    pub fn test_fallible_constructors() {
+        let hex = "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e";
+        let key = SigningKey::from_str(hex).unwrap();
+        assert_eq!(&key.to_bytes()[..], &hex::decode(hex).unwrap()[..]);
+        assert_eq!(SigningKey::from_bytes(&key.to_bytes()).unwrap(), key);
+        assert_eq!(SigningKey::try_from(&key.to_bytes()[..]).unwrap(), key);
+        assert_eq!(
+            SecretKey::from_str(hex).unwrap(),
+            SecretKey::try_from(&key.to_bytes()[..]).unwrap()
+        );
+
+        assert!(matches!(
+            SigningKey::from_bytes(&[0; 56]),
+            Err(LibgoldilockErrors::InvalidLengthError {
+                expected: 57,
+                actual: 56
+            })
+        ));
+        assert!(matches!(
+            SigningKey::from_slice(&[0; 58]),
+            Err(LibgoldilockErrors::InvalidLengthError {
+                expected: 57,
+                actual: 58
+            })
+        ));
+        assert!(matches!(
+            SigningKey::from_str(&hex[..112]),
+            Err(LibgoldilockErrors::InvalidLengthError {
+                expected: 57,
+                actual: 56
+            })
+        ));
+        assert!(matches!(
+            SecretKey::from_str(&hex.replace('c', "x")),
+            Err(LibgoldilockErrors::HexDecodeError(_))
+        ));
+        assert!(matches!(
+            ExpandedSecretKey::try_from(&[0u8; 57][..]),
+            Err(LibgoldilockErrors::InvalidLengthError {
+                expected: 114,
+                actual: 57
+            })
+        ));
+        assert!(matches!(
+            VerifyingKey::from_str("00"),
+            Err(LibgoldilockErrors::InvalidPubkeyLengthError {
+                expected: 57,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            VerifyingKey::try_from(&[0u8; 32][..]),
+            Err(LibgoldilockErrors::InvalidPubkeyLengthError {
+                expected: 57,
+                actual: 32
+            })
+        ));
+
+        let verifying_key =
+            VerifyingKey::from_bytes(key.verifying_key().as_bytes().try_into().unwrap());
+        for len in [0, 10, 57, 113] {
+            assert!(matches!(
+                verifying_key.verify(b"message", &vec![0u8; len]),
+                Err(LibgoldilockErrors::InvalidSignatureLengthError {
+                    expected: 114,
+                    actual
+                }) if actual == len
+            ));
+        }
+    }
+
+    #[test]
+    pub fn test_secret_key_hygiene() {
+        let hex = "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e";
+        let mut key = SigningKey::from_str(hex).unwrap();
+        let expanded = key.secret_key().expand();
+        for debug in [
+            format!("{:?}", key),
+            format!("{:?}", key.secret_key()),
+            format!("{:?}", expanded),
+        ] {
+            assert!(!debug.contains("196"), "{}", debug);
+            assert!(!debug.contains(&hex[..8]), "{}", debug);
+        }
+        assert_eq!(format!("{:?}", key.secret_key()), "SecretKey { .. }");
+
+        let mut secret = key.secret_key().clone();
+        secret.zeroize();
+        assert_eq!(secret.as_bytes(), &[0; 57]);
+        let mut expanded = expanded;
+        expanded.zeroize();
+        assert_eq!(expanded.to_bytes(), [0; 114]);
+        key.zeroize();
+        assert_eq!(key.to_bytes(), [0; 57]);
+    }
 }
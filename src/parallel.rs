@@ -0,0 +1,125 @@
+use rayon::prelude::*;
+
+use crate::errors::LibgoldilockErrors;
+use crate::goldilocks::ed448_verify;
+use crate::{Signature, VerifyingKey};
+
+/// Verifies every (key, message, signature) triple on the rayon thread pool
+/// under `VerificationPolicy::RFC8032` and returns one result per item, in
+/// order.
+pub fn par_verify_each(
+    items: &[(VerifyingKey, &[u8], Signature)],
+) -> Vec<Result<(), LibgoldilockErrors>> {
+    items
+        .par_iter()
+        .map(|(key, message, signature)| key.verify(message, signature))
+        .collect()
+}
+
+/// Like `par_verify_each`, but only reports whether all items verify. The
+/// indices of the bad items are returned in `BatchVerificationError`.
+pub fn par_verify_all(
+    items: &[(VerifyingKey, &[u8], Signature)],
+) -> Result<(), LibgoldilockErrors> {
+    let results = par_verify_each(items);
+    invalid_items(results.iter().map(|result| result.is_ok()))
+}
+
+/// `ed448_verify` over raw (public key, signature, message) triples, on the
+/// rayon thread pool. Returns one result per item, in order.
+pub fn par_ed448_verify_each(
+    items: &[(&[u8], &[u8], &[u8])],
+) -> Vec<Result<bool, LibgoldilockErrors>> {
+    items
+        .par_iter()
+        .map(|(pubkey, sig, message)| ed448_verify(pubkey, sig, message))
+        .collect()
+}
+
+/// Like `par_ed448_verify_each`, but only reports whether all items verify.
+/// An item that fails to verify or cannot be decoded is bad, and the indices
+/// of the bad items are returned in `BatchVerificationError`.
+pub fn par_ed448_verify_all(items: &[(&[u8], &[u8], &[u8])]) -> Result<(), LibgoldilockErrors> {
+    let results = par_ed448_verify_each(items);
+    invalid_items(results.iter().map(|result| matches!(result, Ok(true))))
+}
+
+fn invalid_items<I: Iterator<Item = bool>>(valid: I) -> Result<(), LibgoldilockErrors> {
+    let invalid: Vec<usize> = valid
+        .enumerate()
+        .filter(|(_, ok)| !ok)
+        .map(|(i, _)| i)
+        .collect();
+    if invalid.is_empty() {
+        return Ok(());
+    }
+    Err(LibgoldilockErrors::BatchVerificationError(invalid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goldilocks::{ed448_derive_public, ed448_generate_key, ed448_sign};
+    use crate::SigningKey;
+
+    #[test]
+    pub fn test_par_verify() {
+        let mut rng = rand::thread_rng();
+        let messages: Vec<Vec<u8>> = (0..16u8).map(|i| vec![i; i as usize]).collect();
+        let mut items: Vec<(VerifyingKey, &[u8], Signature)> = messages
+            .iter()
+            .map(|message| {
+                let key = SigningKey::random(&mut rng);
+                let sig = key.sign_with_context(message, &[]).unwrap();
+                (key.verifying_key().clone(), message.as_slice(), sig)
+            })
+            .collect();
+        assert!(par_verify_each(&items).iter().all(|result| result.is_ok()));
+        assert!(par_verify_all(&items).is_ok());
+
+        items[3].1 = b"forged";
+        items[11].1 = b"forged";
+        let results = par_verify_each(&items);
+        assert!(matches!(
+            results[3],
+            Err(LibgoldilockErrors::InvalidSignatureError)
+        ));
+        assert!(results[4].is_ok());
+        match par_verify_all(&items) {
+            Err(LibgoldilockErrors::BatchVerificationError(invalid)) => {
+                assert_eq!(invalid, vec![3, 11])
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    pub fn test_par_ed448_verify_each() {
+        let pk = ed448_generate_key();
+        let public = ed448_derive_public(&pk);
+        let sig = ed448_sign(&pk, b"message");
+        let items: [(&[u8], &[u8], &[u8]); 3] = [
+            (&public, &sig, b"message"),
+            (&public, &sig, b"other"),
+            (&public[..56], &sig, b"message"),
+        ];
+        let results = par_ed448_verify_each(&items);
+        assert!(results[0].as_ref().unwrap());
+        assert!(!results[1].as_ref().unwrap());
+        assert!(matches!(
+            results[2],
+            Err(LibgoldilockErrors::InvalidPubkeyLengthError {
+                expected: 57,
+                actual: 56
+            })
+        ));
+
+        assert!(par_ed448_verify_all(&items[..1]).is_ok());
+        match par_ed448_verify_all(&items) {
+            Err(LibgoldilockErrors::BatchVerificationError(invalid)) => {
+                assert_eq!(invalid, vec![1, 2])
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
@@ -1,5 +0,0 @@
-use crate::errors::LibgoldilockErrors;
-
-pub trait PrehashSigner<S> {
-    fn sign_prehash(&self, prehash: &[u8]) -> Result<S, LibgoldilockErrors>;
-}
\ No newline at end of file
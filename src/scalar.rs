@@ -1,7 +1,8 @@
 use crate::constants32::{
-    self, Dword, Sdword, Word, MONTGOMERY_FACTOR, SCALAR_BYTES, SCALAR_LIMBS, SCALAR_R2,
-    SCALAR_SER_BYTES, SCALAR_WORDS, WORD_BITS,
+    self, Dword, Sdword, Word, MONTGOMERY_FACTOR, SCALAR_BITS, SCALAR_BYTES, SCALAR_LIMBS,
+    SCALAR_R2, SCALAR_SER_BYTES, SCALAR_WORDS, WORD_BITS,
 };
+use crate::errors::LibgoldilockErrors;
 
 const SCALAR_Q: [Word; SCALAR_WORDS] = constants32::SCALAR_Q;
 
@@ -185,6 +186,33 @@ pub fn encode(s: &Scalar) -> [u8; 57] {
     dst
 }
 
+// Decodes a 57-byte little-endian scalar, rejecting values >= L and a
+// nonzero final byte instead of reducing them.
+pub fn decode_canonical(b: &[u8]) -> Result<Scalar, LibgoldilockErrors> {
+    if b.len() != SCALAR_BYTES + 1 {
+        return Err(LibgoldilockErrors::InvalidLengthError {
+            expected: SCALAR_BYTES + 1,
+            actual: b.len(),
+        });
+    }
+    if b[SCALAR_BYTES] != 0 {
+        return Err(LibgoldilockErrors::NonCanonicalScalarError);
+    }
+    let s = decode_short(b, SCALAR_BYTES);
+
+    // The final borrow of s - L is -1 exactly when s < L.
+    let mut accum = 0 as Sdword;
+    for i in 0..SCALAR_LIMBS {
+        accum += s[i] as Sdword - SCALAR_Q[i] as Sdword;
+        accum >>= WORD_BITS;
+    }
+    if accum != -1 {
+        return Err(LibgoldilockErrors::NonCanonicalScalarError);
+    }
+
+    Ok(s)
+}
+
 pub fn decode_long(b: &[u8]) -> Scalar {
     let mut y = create_zero_scalar();
     let b_len = b.len();
@@ -216,11 +244,27 @@ pub fn decode_long(b: &[u8]) -> Scalar {
     res
 }
 
+// x^(L - 2), which is 1/x for nonzero x and zero for x = 0. The exponent is
+// public, so the square-and-multiply chain does not depend on x.
+pub fn invert(x: &Scalar) -> Scalar {
+    let exponent = sub(&SCALAR_Q, &set(2));
+    let mut r = set(1);
+    for i in (0..SCALAR_BITS).rev() {
+        r = mul(&r, &r);
+        if (exponent[i / WORD_BITS] >> (i % WORD_BITS)) & 1 == 1 {
+            r = mul(&r, x);
+        }
+    }
+
+    r
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         constants32::{SCALAR_R2, SCALAR_ZERO},
-        scalar::{add, copy, halve, mul, set, sub, Scalar, SCALAR_Q},
+        errors::LibgoldilockErrors,
+        scalar::{add, copy, decode_canonical, halve, mul, set, sub, Scalar, SCALAR_Q},
     };
 
     use super::{decode_long, encode, montgomery_multiply};
@@ -397,4 +441,35 @@ mod tests {
         let res = encode(&sc);
         assert_eq!(res, exp);
     }
+
+    #[test]
+    pub fn test_decode_canonical() {
+        let mut order: [u8; 57] = [0; 57];
+        for (i, word) in SCALAR_Q.iter().enumerate() {
+            order[4 * i..4 * i + 4].copy_from_slice(&word.to_le_bytes());
+        }
+        assert!(matches!(
+            decode_canonical(&order),
+            Err(LibgoldilockErrors::NonCanonicalScalarError)
+        ));
+
+        order[0] -= 1;
+        let s = decode_canonical(&order).unwrap();
+        assert_eq!(encode(&s), order);
+        assert_eq!(decode_canonical(&[0; 57]).unwrap(), SCALAR_ZERO);
+
+        let mut high_byte: [u8; 57] = [0; 57];
+        high_byte[56] = 1;
+        assert!(matches!(
+            decode_canonical(&high_byte),
+            Err(LibgoldilockErrors::NonCanonicalScalarError)
+        ));
+        assert!(matches!(
+            decode_canonical(&[0; 56]),
+            Err(LibgoldilockErrors::InvalidLengthError {
+                expected: 57,
+                actual: 56
+            })
+        ));
+    }
 }
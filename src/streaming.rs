@@ -0,0 +1,478 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use sha3::digest::{
+    consts::U64, ExtendableOutput, FixedOutput, HashMarker, Output, OutputSizeUser, Update,
+};
+pub use sha3::Shake256;
+use zeroize::Zeroizing;
+
+use crate::eddsa::{
+    check_context, decode_pubkey, decode_signature_point, decode_signature_scalar, dom_hasher,
+    verify_equation, PREHASH_LENGTH,
+};
+use crate::errors::LibgoldilockErrors;
+use crate::extended_point::checked_precomputed_scalar_mul;
+use crate::goldilocks::{expand_seed, nonce_from_hash, signature_from_challenge};
+use crate::scalar::{decode_long, halve};
+use crate::{Signature, SigningKey, VerifyingKey};
+
+#[derive(Clone, Default)]
+struct Prehasher {
+    hasher: Shake256,
+    context: Vec<u8>,
+}
+
+impl Prehasher {
+    fn new(hasher: Shake256, context: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        check_context(context)?;
+
+        Ok(Self {
+            hasher,
+            context: context.to_vec(),
+        })
+    }
+
+    fn finalize(self) -> ([u8; PREHASH_LENGTH], Vec<u8>) {
+        let mut prehash: [u8; PREHASH_LENGTH] = [0; PREHASH_LENGTH];
+        self.hasher.finalize_xof_into(&mut prehash);
+
+        (prehash, self.context)
+    }
+}
+
+/// Incremental Ed448ph signer: the message is fed through `update` or
+/// `io::Write` and only its SHAKE256 digest is signed.
+#[derive(Clone, Default)]
+pub struct Ed448phSigner {
+    state: Prehasher,
+}
+
+impl Ed448phSigner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_context(context: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        Self::from_hasher_with_context(Shake256::default(), context)
+    }
+
+    /// Starts from a hasher that has already absorbed the start of the
+    /// message, with an empty context.
+    pub fn from_hasher(hasher: Shake256) -> Self {
+        Self {
+            state: Prehasher {
+                hasher,
+                context: Vec::new(),
+            },
+        }
+    }
+
+    pub fn from_hasher_with_context(
+        hasher: Shake256,
+        context: &[u8],
+    ) -> Result<Self, LibgoldilockErrors> {
+        Ok(Self {
+            state: Prehasher::new(hasher, context)?,
+        })
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.hasher.update(data);
+    }
+
+    pub fn finalize(self, key: &SigningKey) -> Result<Signature, LibgoldilockErrors> {
+        let (prehash, context) = self.state.finalize();
+        key.sign_prehashed_with_context(&prehash, &context)
+    }
+}
+
+impl Write for Ed448phSigner {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Incremental Ed448ph verifier, the counterpart of `Ed448phSigner`.
+#[derive(Clone, Default)]
+pub struct Ed448phVerifier {
+    state: Prehasher,
+}
+
+impl Ed448phVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_context(context: &[u8]) -> Result<Self, LibgoldilockErrors> {
+        Self::from_hasher_with_context(Shake256::default(), context)
+    }
+
+    /// Starts from a hasher that has already absorbed the start of the
+    /// message, with an empty context.
+    pub fn from_hasher(hasher: Shake256) -> Self {
+        Self {
+            state: Prehasher {
+                hasher,
+                context: Vec::new(),
+            },
+        }
+    }
+
+    pub fn from_hasher_with_context(
+        hasher: Shake256,
+        context: &[u8],
+    ) -> Result<Self, LibgoldilockErrors> {
+        Ok(Self {
+            state: Prehasher::new(hasher, context)?,
+        })
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.hasher.update(data);
+    }
+
+    pub fn finalize(self, key: &VerifyingKey, signature: &[u8]) -> Result<(), LibgoldilockErrors> {
+        let (prehash, context) = self.state.finalize();
+        key.verify_prehashed_with_context(&prehash, signature, &context)
+    }
+}
+
+impl Write for Ed448phVerifier {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// SHAKE256 with the 64-byte output of Ed448ph, usable wherever a fixed-output
+/// `Digest` is expected (`signature::DigestSigner` and `DigestVerifier`).
+#[derive(Clone, Default)]
+pub struct Shake256Digest(Shake256);
+
+impl Shake256Digest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<Shake256> for Shake256Digest {
+    fn from(hasher: Shake256) -> Self {
+        Self(hasher)
+    }
+}
+
+impl HashMarker for Shake256Digest {}
+
+impl OutputSizeUser for Shake256Digest {
+    type OutputSize = U64;
+}
+
+impl Update for Shake256Digest {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+impl FixedOutput for Shake256Digest {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.0.finalize_xof_into(out);
+    }
+}
+
+impl Write for Shake256Digest {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Hashes everything left in `reader`, also returning a plain SHAKE256
+// digest of the bytes read so that two passes can be compared.
+fn hash_reader<R: Read>(
+    mut hasher: Shake256,
+    reader: &mut R,
+) -> io::Result<([u8; 114], [u8; PREHASH_LENGTH])> {
+    let mut message = Shake256::default();
+    let mut buf: [u8; 8192] = [0; 8192];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        hasher.update(&buf[..n]);
+        message.update(&buf[..n]);
+    }
+
+    let mut out: [u8; 114] = [0; 114];
+    hasher.finalize_xof_into(&mut out);
+    let mut digest: [u8; PREHASH_LENGTH] = [0; PREHASH_LENGTH];
+    message.finalize_xof_into(&mut digest);
+
+    Ok((out, digest))
+}
+
+/// Signs the rest of `reader` with pure Ed448, without buffering the message.
+///
+/// The message is read twice from the current position: once for the nonce
+/// and once for the challenge. If the two passes saw different bytes, the
+/// same nonce would be used with two different challenges, and the private
+/// key can be computed from such a signature and an honest one. A digest of
+/// each pass is therefore compared before the response is computed, and
+/// `MessageChangedError` is returned if they differ. The reader should still
+/// be a source nothing else writes to while signing, such as a private
+/// buffer or a file that is not being modified.
+///
+/// With a hardened `key` the checks of `SigningKey::with_hardening` apply:
+/// the public key and nonce point are recomputed, and the signature is
+/// verified over a third pass, which must also see the same bytes, before
+/// it is returned.
+pub fn sign_reader<R: Read + Seek>(
+    key: &SigningKey,
+    reader: &mut R,
+    context: &[u8],
+) -> Result<Signature, LibgoldilockErrors> {
+    check_context(context)?;
+    let start = reader.stream_position()?;
+    let public = &key.verifying_key().key;
+
+    let (secret, prefix) = expand_seed(&key.secret_key().key);
+    let (secret, prefix) = (Zeroizing::new(secret), Zeroizing::new(prefix));
+    let sec = Zeroizing::new(decode_long(&secret[..]));
+
+    let mut hasher = dom_hasher(false, context);
+    hasher.update(&prefix[..]);
+    let (nonce, first_pass) = hash_reader(hasher, reader)?;
+    let nonce = Zeroizing::new(nonce);
+    let (nonce_scalar, nonce_point) = nonce_from_hash(&nonce);
+    let nonce_scalar = Zeroizing::new(nonce_scalar);
+
+    reader.seek(SeekFrom::Start(start))?;
+    let mut hasher = dom_hasher(false, context);
+    hasher.update(&nonce_point);
+    hasher.update(public);
+    let (challenge, second_pass) = hash_reader(hasher, reader)?;
+    if first_pass != second_pass {
+        return Err(LibgoldilockErrors::MessageChangedError);
+    }
+
+    let sig = signature_from_challenge(&challenge, &sec, &nonce_scalar, &nonce_point);
+    if key.is_hardened() {
+        let public_check = checked_precomputed_scalar_mul(halve(halve(*sec)))?;
+        let nonce_check = checked_precomputed_scalar_mul(halve(halve(*nonce_scalar)))?;
+        if public_check.eddsa_like_encode() != *public
+            || nonce_check.eddsa_like_encode() != nonce_point
+        {
+            return Err(LibgoldilockErrors::FaultDetectedError);
+        }
+
+        reader.seek(SeekFrom::Start(start))?;
+        let mut hasher = dom_hasher(false, context);
+        hasher.update(&sig[..57]);
+        hasher.update(public);
+        let (challenge, third_pass) = hash_reader(hasher, reader)?;
+        if third_pass != first_pass {
+            return Err(LibgoldilockErrors::MessageChangedError);
+        }
+        let valid = decode_pubkey(public).and_then(|p| {
+            Ok(verify_equation(
+                &p,
+                &decode_signature_point(&sig)?,
+                &decode_signature_scalar(&sig)?,
+                &challenge,
+            ))
+        });
+        if !matches!(valid, Ok(true)) {
+            return Err(LibgoldilockErrors::FaultDetectedError);
+        }
+    }
+
+    Ok(Signature { sig })
+}
+
+/// Verifies a pure Ed448 signature over the rest of `reader`, reading it
+/// once. Returns `Ok(false)` when the group equation does not hold, and an
+/// error when the key, signature or context cannot be used; the policy is
+/// that of `VerificationPolicy::RFC8032`.
+pub fn verify_reader<R: Read>(
+    pubkey: &[u8],
+    sig: &[u8],
+    reader: &mut R,
+    context: &[u8],
+) -> Result<bool, LibgoldilockErrors> {
+    check_context(context)?;
+
+    let p = decode_pubkey(pubkey)?;
+    let r_point = decode_signature_point(sig)?;
+    let response = decode_signature_scalar(sig)?;
+
+    let mut hasher = dom_hasher(false, context);
+    hasher.update(&sig[..57]);
+    hasher.update(&pubkey[..57]);
+    let (challenge, _) = hash_reader(hasher, reader)?;
+
+    Ok(verify_equation(&p, &r_point, &response, &challenge))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goldilocks::{ed448_sign, ed448ph_prehash, ed448ph_sign, hex_to_signature};
+    use std::io::Cursor;
+    use std::str::FromStr;
+
+    #[test]
+    pub fn test_ed448ph_signer_rfc8032() {
+        let key = SigningKey::from_str("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49").unwrap();
+        let mut signer = Ed448phSigner::with_context(b"foo").unwrap();
+        signer.write_all(b"a").unwrap();
+        signer.write_all(b"bc").unwrap();
+        let sig = signer.finalize(&key).unwrap();
+        let exp = hex_to_signature("c32299d46ec8ff02b54540982814dce9a05812f81962b649d528095916a2aa481065b1580423ef927ecf0af5888f90da0f6a9a85ad5dc3f280d91224ba9911a3653d00e484e2ce232521481c8658df304bb7745a73514cdb9bf3e15784ab71284f8d0704a608c54a6b62d97beb511d132100").unwrap();
+        assert_eq!(&sig.as_slice()[..114], &exp);
+
+        let mut verifier = Ed448phVerifier::with_context(b"foo").unwrap();
+        verifier.update(b"ab");
+        verifier.update(b"c");
+        assert!(verifier.finalize(key.verifying_key(), &exp).is_ok());
+    }
+
+    #[test]
+    pub fn test_ed448ph_signer_chunks() {
+        let key = SigningKey::random(&mut rand::thread_rng());
+        let message: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+
+        let mut signer = Ed448phSigner::new();
+        io::copy(&mut &message[..], &mut signer).unwrap();
+        let sig = signer.finalize(&key).unwrap();
+        let exp = ed448ph_sign(&key.to_bytes(), &ed448ph_prehash(&message), &[]).unwrap();
+        assert_eq!(&sig.as_slice()[..114], &exp);
+
+        let mut verifier = Ed448phVerifier::new();
+        for chunk in message.chunks(4096) {
+            verifier.write_all(chunk).unwrap();
+        }
+        assert!(verifier.clone().finalize(key.verifying_key(), &exp).is_ok());
+        verifier.update(b"!");
+        assert!(matches!(
+            verifier.finalize(key.verifying_key(), &exp),
+            Err(LibgoldilockErrors::InvalidSignatureError)
+        ));
+    }
+
+    #[test]
+    pub fn test_ed448ph_signer_from_hasher() {
+        let key = SigningKey::random(&mut rand::thread_rng());
+        let mut hasher = Shake256::default();
+        hasher.update(b"The quick brown fox ");
+
+        let mut signer = Ed448phSigner::from_hasher(hasher.clone());
+        signer.update(b"jumps over the lazy dog");
+        let sig = signer.finalize(&key).unwrap();
+
+        let mut verifier = Ed448phVerifier::from_hasher(hasher);
+        verifier.update(b"jumps over the lazy dog");
+        assert!(verifier
+            .finalize(key.verifying_key(), sig.as_slice())
+            .is_ok());
+
+        let prehash = ed448ph_prehash(b"The quick brown fox jumps over the lazy dog");
+        assert!(key
+            .verifying_key()
+            .verify_prehashed_with_context(&prehash, sig.as_slice(), &[])
+            .is_ok());
+        assert!(matches!(
+            Ed448phSigner::with_context(&[0; 256]),
+            Err(LibgoldilockErrors::ContextTooLongError { .. })
+        ));
+    }
+
+    #[test]
+    pub fn test_sign_reader_matches_ed448_sign() {
+        let message: Vec<u8> = (0..50_000).map(|i| (i % 253) as u8).collect();
+        for _i in 0..5 {
+            let key = SigningKey::random(&mut rand::thread_rng());
+            let mut reader = Cursor::new(&message);
+            let sig = sign_reader(&key, &mut reader, &[]).unwrap();
+            assert_eq!(sig.to_bytes(), ed448_sign(&key.to_bytes(), &message));
+
+            let public = key.verifying_key().as_bytes();
+            assert!(verify_reader(public, sig.as_slice(), &mut &message[..], &[]).unwrap());
+            assert!(!verify_reader(public, sig.as_slice(), &mut &message[1..], &[]).unwrap());
+        }
+    }
+
+    #[test]
+    pub fn test_sign_reader_rfc8032_context() {
+        let key = SigningKey::from_str("c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e").unwrap();
+        // The message starts after a two byte header the reader is positioned past.
+        let mut reader = Cursor::new([0xff, 0xff, 0x03]);
+        reader.set_position(2);
+        let sig = sign_reader(&key, &mut reader, b"foo").unwrap();
+        assert_eq!(sig, key.sign_with_context(&[0x03], b"foo").unwrap());
+
+        let public = key.verifying_key().as_bytes();
+        assert!(verify_reader(public, sig.as_slice(), &mut Cursor::new([0x03]), b"foo").unwrap());
+        assert!(!verify_reader(public, sig.as_slice(), &mut Cursor::new([0x03]), b"").unwrap());
+        assert!(matches!(
+            sign_reader(&key, &mut reader, &[0; 256]),
+            Err(LibgoldilockErrors::ContextTooLongError { .. })
+        ));
+    }
+
+    #[test]
+    pub fn test_sign_reader_hardened() {
+        let message: Vec<u8> = (0..20_000).map(|i| (i % 241) as u8).collect();
+        let key = SigningKey::random(&mut rand::thread_rng()).with_hardening(true);
+        let mut reader = Cursor::new(&message);
+        reader.set_position(7);
+        let sig = sign_reader(&key, &mut reader, b"ctx").unwrap();
+        assert_eq!(sig, key.sign_with_context(&message[7..], b"ctx").unwrap());
+        assert!(key
+            .verifying_key()
+            .verify_with_context(&message[7..], sig.as_slice(), b"ctx")
+            .is_ok());
+    }
+
+    // A seekable reader whose contents change every time it is rewound.
+    struct ChangingReader(Cursor<Vec<u8>>);
+
+    impl Read for ChangingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.0.read(buf)
+        }
+    }
+
+    impl Seek for ChangingReader {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.0.get_mut()[0] ^= 1;
+            self.0.seek(pos)
+        }
+    }
+
+    #[test]
+    pub fn test_sign_reader_message_changed() {
+        for hardened in [false, true] {
+            let key = SigningKey::random(&mut rand::thread_rng()).with_hardening(hardened);
+            let mut reader = ChangingReader(Cursor::new(b"attack at dawn".to_vec()));
+            assert!(matches!(
+                sign_reader(&key, &mut reader, &[]),
+                Err(LibgoldilockErrors::MessageChangedError)
+            ));
+        }
+    }
+}